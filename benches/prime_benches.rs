@@ -1,4 +1,7 @@
-use const_primes::{is_prime, primes, primes_geq, primes_lt, sieve, sieve_geq, sieve_lt};
+use const_primes::{
+    is_prime, is_prime_u32, primes, primes_geq, primes_lt, sieve, sieve_geq, sieve_lt, sieve_wheel,
+    BitSieve, Primes,
+};
 use criterion::{criterion_group, criterion_main, BatchSize, Criterion, Throughput};
 use rand::{rngs::SmallRng, Rng, SeedableRng};
 use std::hint::black_box;
@@ -36,6 +39,35 @@ fn benchmarks(c: &mut Criterion) {
         });
     }
 
+    {
+        const N: u64 = 10_000;
+        let mut rng = SmallRng::seed_from_u64(1234567890);
+        let mut u32_primality_testing = c.benchmark_group("u32 primality testing");
+        u32_primality_testing.throughput(Throughput::Elements(N));
+        u32_primality_testing.bench_function(format!("is_prime on {N} random u32s"), |b| {
+            b.iter_batched(
+                || (0..N).map(|_| rng.gen()).collect::<Vec<u32>>(),
+                |data| {
+                    for &number in &data {
+                        black_box(is_prime(number as u64));
+                    }
+                },
+                BatchSize::SmallInput,
+            )
+        });
+        u32_primality_testing.bench_function(format!("is_prime_u32 on {N} random u32s"), |b| {
+            b.iter_batched(
+                || (0..N).map(|_| rng.gen()).collect::<Vec<u32>>(),
+                |data| {
+                    for &number in &data {
+                        black_box(is_prime_u32(number));
+                    }
+                },
+                BatchSize::SmallInput,
+            )
+        });
+    }
+
     {
         const N: usize = 10_000;
         let mut sieving = c.benchmark_group("prime sieving");
@@ -48,6 +80,48 @@ fn benchmarks(c: &mut Criterion) {
         sieving.bench_function(format!("{N} integers >= 99990000"), |b| {
             b.iter(|| black_box(sieve_geq::<N, N>(99990000)))
         });
+        sieving.bench_function(format!("first {N} integers, wheel"), |b| {
+            b.iter(|| black_box(sieve_wheel::<N>()))
+        });
+
+        // `BitSieve` packs `WORDS * 64` numbers into a `[u64; WORDS]`, covering roughly the same
+        // range as `sieve::<N>()` while using an eighth of the stack space.
+        const WORDS: usize = N.div_ceil(64);
+        sieving.bench_function(format!("first {N} integers, bit-packed"), |b| {
+            b.iter(|| black_box(BitSieve::<WORDS>::new_sieve()))
+        });
+        sieving.bench_function(
+            format!("first {N} integers, bit-packed, unpacked to bools"),
+            |b| b.iter(|| black_box(BitSieve::<WORDS>::new_sieve().to_bool_array::<N>())),
+        );
+    }
+
+    {
+        const N: usize = 100_000;
+        let cache: Primes<N> = Primes::new();
+        const NUM_QUERIES: usize = 1_000;
+
+        let mut rng = SmallRng::seed_from_u64(192837465);
+        let query_points: Vec<u32> = (0..NUM_QUERIES)
+            .map(|_| rng.gen_range(0..=*cache.last()))
+            .collect();
+
+        let mut counting = c.benchmark_group("count primes leq");
+        counting.throughput(Throughput::Elements(NUM_QUERIES as u64));
+        counting.bench_function(format!("binary search over {N} cached primes"), |b| {
+            b.iter(|| {
+                for &x in &query_points {
+                    black_box(cache.prime_pi(x));
+                }
+            })
+        });
+        counting.bench_function(format!("linear scan over {N} cached primes"), |b| {
+            b.iter(|| {
+                for &x in &query_points {
+                    black_box(cache.as_slice().iter().filter(|&&p| p <= x).count());
+                }
+            })
+        });
     }
 }
 
@@ -0,0 +1,271 @@
+//! This module contains the implementation of [`ArraySection`], a fixed-size array together with
+//! a length describing how many of its leading elements are populated.
+
+use core::iter::FusedIterator;
+
+/// A fixed-capacity array of size `N` together with a count of how many of its
+/// leading elements are meaningful.
+///
+/// Returned by functions that may find fewer than `N` items of interest without
+/// that being an error, such as [`primes_lt_filled`](crate::primes_lt_filled).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ArraySection<T, const N: usize> {
+    array: [T; N],
+    len: usize,
+}
+
+impl<T, const N: usize> ArraySection<T, N> {
+    pub(crate) const fn new(array: [T; N], len: usize) -> Self {
+        Self { array, len }
+    }
+
+    /// Returns a slice of the populated elements.
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn as_slice(&self) -> &[T] {
+        self.array.split_at(self.len).0
+    }
+
+    /// Returns a reference to the element at `index`, or [`None`] if `index` is
+    /// past the populated length.
+    ///
+    /// Unlike indexing into [`as_slice`](Self::as_slice) with `[]`, this never panics,
+    /// which makes it convenient for iterating over a section of unknown length by index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::primes_lt_filled;
+    /// let (section, _) = primes_lt_filled::<5, 10>(10);
+    /// assert_eq!(section.get(0), Some(&2));
+    /// assert_eq!(section.get(2), Some(&5));
+    /// assert_eq!(section.get(4), None);
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len {
+            Some(&self.array[index])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of populated elements.
+    #[inline]
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether there are no populated elements.
+    #[inline]
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns whether all `N` elements of the underlying array are populated.
+    #[inline]
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+}
+
+impl<const N: usize> ArraySection<u64, N> {
+    /// Returns the smallest populated element that is greater than or equal to `x`,
+    /// or [`None`] if there is none.
+    ///
+    /// Uses a binary search, which requires the populated elements to be sorted in ascending
+    /// order. This holds for the [`ArraySection`]s returned by this crate's prime generators,
+    /// such as [`primes_between`](crate::primes_between), but is not checked here.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::primes_between;
+    /// let primes = primes_between!(100, 120); // [101, 103, 107, 109, 113]
+    /// assert_eq!(primes.smallest_geq(104), Some(107));
+    /// assert_eq!(primes.smallest_geq(113), Some(113));
+    /// assert_eq!(primes.smallest_geq(114), None);
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn smallest_geq(&self, x: u64) -> Option<u64> {
+        let slice = self.as_slice();
+        let mut left = 0;
+        let mut right = slice.len();
+        while left < right {
+            let mid = left + (right - left) / 2;
+            if slice[mid] < x {
+                left = mid + 1;
+            } else {
+                right = mid;
+            }
+        }
+
+        if left < slice.len() {
+            Some(slice[left])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the largest populated element that is smaller than or equal to `x`,
+    /// or [`None`] if there is none.
+    ///
+    /// Uses a binary search, with the same sorted precondition as
+    /// [`smallest_geq`](Self::smallest_geq).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::primes_between;
+    /// let primes = primes_between!(100, 120); // [101, 103, 107, 109, 113]
+    /// assert_eq!(primes.largest_leq(108), Some(107));
+    /// assert_eq!(primes.largest_leq(101), Some(101));
+    /// assert_eq!(primes.largest_leq(100), None);
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn largest_leq(&self, x: u64) -> Option<u64> {
+        let slice = self.as_slice();
+        let mut left = 0;
+        let mut right = slice.len();
+        while left < right {
+            let mid = left + (right - left) / 2;
+            if slice[mid] <= x {
+                left = mid + 1;
+            } else {
+                right = mid;
+            }
+        }
+
+        if left == 0 {
+            None
+        } else {
+            Some(slice[left - 1])
+        }
+    }
+}
+
+impl<T, const N: usize> IntoIterator for ArraySection<T, N> {
+    type Item = T;
+    type IntoIter = ArraySectionIntoIter<T, N>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len;
+        ArraySectionIntoIter(self.array.into_iter().take(len))
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a ArraySection<T, N> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice().iter()
+    }
+}
+
+/// An owning iterator over the populated elements of an [`ArraySection`].
+///
+/// Created by the [`IntoIterator`] implementation on [`ArraySection`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ArraySectionIntoIter<T, const N: usize>(core::iter::Take<core::array::IntoIter<T, N>>);
+
+impl<T, const N: usize> Iterator for ArraySectionIntoIter<T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for ArraySectionIntoIter<T, N> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<T, const N: usize> FusedIterator for ArraySectionIntoIter<T, N> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_array_section() {
+        let section = ArraySection::new([1, 2, 3, 0, 0], 3);
+        assert_eq!(section.as_slice(), &[1, 2, 3]);
+        assert_eq!(section.len(), 3);
+        assert!(!section.is_empty());
+        assert!(!section.is_full());
+        let mut iter = section.into_iter();
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next(), None);
+    }
+
+    // There is no `SegmentedGenerationResult` type in this crate; `ArraySection` and its
+    // owning iterator play that role, and `ArraySectionIntoIter` already derives `Clone`,
+    // so double-pass consumption (e.g. sum then max) works without rebuilding the section.
+    #[test]
+    fn array_section_into_iter_supports_double_pass_via_clone() {
+        let section = ArraySection::new([2u32, 3, 5, 7, 0], 4);
+        let iter = section.into_iter();
+        let sum: u32 = iter.clone().sum();
+        let max = iter.max();
+        assert_eq!(sum, 17);
+        assert_eq!(max, Some(7));
+    }
+
+    // There is no `SegmentedGenerationResult` type in this crate; `ArraySection` plays that
+    // role, and it has no panicking `Index` impl to begin with, so `get` is simply added
+    // alongside `as_slice` as the panic-free way to read a single populated element by index.
+    #[test]
+    fn check_array_section_get() {
+        let section = ArraySection::new([2, 3, 5, 0, 0], 3);
+        assert_eq!(section.get(0), Some(&2));
+        assert_eq!(section.get(2), Some(&5));
+        assert_eq!(section.get(3), None);
+        assert_eq!(section.get(4), None);
+
+        let full: ArraySection<u8, 2> = ArraySection::new([1, 2], 2);
+        assert_eq!(full.get(1), Some(&2));
+        assert_eq!(full.get(2), None);
+    }
+
+    #[test]
+    fn check_array_section_smallest_geq_and_largest_leq() {
+        let section: ArraySection<u64, 5> = ArraySection::new([101, 103, 107, 109, 113], 5);
+        assert_eq!(section.smallest_geq(104), Some(107));
+        assert_eq!(section.smallest_geq(113), Some(113));
+        assert_eq!(section.smallest_geq(114), None);
+        assert_eq!(section.smallest_geq(0), Some(101));
+
+        assert_eq!(section.largest_leq(108), Some(107));
+        assert_eq!(section.largest_leq(101), Some(101));
+        assert_eq!(section.largest_leq(100), None);
+        assert_eq!(section.largest_leq(1_000), Some(113));
+
+        let empty: ArraySection<u64, 5> = ArraySection::new([0; 5], 0);
+        assert_eq!(empty.smallest_geq(1), None);
+        assert_eq!(empty.largest_leq(1), None);
+    }
+
+    #[test]
+    fn check_array_section_full_and_empty() {
+        let full: ArraySection<u8, 2> = ArraySection::new([1, 2], 2);
+        assert!(full.is_full());
+        let empty: ArraySection<u8, 2> = ArraySection::new([0, 0], 0);
+        assert!(empty.is_empty());
+        assert_eq!(empty.as_slice(), &[] as &[u8]);
+    }
+}
@@ -0,0 +1,174 @@
+//! This module contains the implementation of [`BitSieve`], a bit-packed alternative to
+//! [`sieve`](crate::sieve) for when `MEM` would otherwise need to be large enough to put a
+//! `[bool; MEM]` sieve at risk of overflowing the stack.
+
+use crate::isqrt;
+
+/// Clears bit `i` of `words`, treating it as a single contiguous bitfield of `WORDS * 64` bits.
+const fn clear_bit<const WORDS: usize>(words: &mut [u64; WORDS], i: usize) {
+    words[i / 64] &= !(1u64 << (i % 64));
+}
+
+/// Returns whether bit `i` of `words` is set, treating it as a single contiguous bitfield of
+/// `WORDS * 64` bits.
+const fn bit_is_set<const WORDS: usize>(words: &[u64; WORDS], i: usize) -> bool {
+    (words[i / 64] >> (i % 64)) & 1 == 1
+}
+
+/// A sieve of Eratosthenes packed one bit per number instead of one `bool` per number.
+///
+/// [`sieve`](crate::sieve) stores its output as `[bool; N]`, which on most platforms spends a
+/// full byte per number. `BitSieve<WORDS>` instead packs `WORDS * 64` numbers into `[u64; WORDS]`,
+/// using 8 times less stack space for the same range, at the cost of needing [`is_set`](Self::is_set)
+/// instead of plain indexing to read a single number's primality.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::BitSieve;
+/// const SIEVE: BitSieve<2> = BitSieve::new_sieve(); // covers the range 0..128
+/// assert!(!SIEVE.is_set(0));
+/// assert!(!SIEVE.is_set(1));
+/// assert!(SIEVE.is_set(2));
+/// assert!(SIEVE.is_set(127));
+/// assert!(!SIEVE.is_set(4));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BitSieve<const WORDS: usize> {
+    words: [u64; WORDS],
+}
+
+impl<const WORDS: usize> BitSieve<WORDS> {
+    /// Returns a bit-packed sieve of Eratosthenes covering the range `0..WORDS * 64`, where a set
+    /// bit means the corresponding number is prime.
+    ///
+    /// This runs the same algorithm as [`sieve`](crate::sieve), crossing off the multiples of each
+    /// prime starting from its square, just
+    /// writing its output one bit at a time instead of one `bool` at a time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::BitSieve;
+    /// const SIEVE: BitSieve<1> = BitSieve::new_sieve(); // covers the range 0..64
+    /// assert_eq!(SIEVE.to_bool_array(), const_primes::sieve::<64>());
+    /// ```
+    #[must_use = "the function only returns a new value"]
+    pub const fn new_sieve() -> Self {
+        let total = WORDS * 64;
+        let mut words = [u64::MAX; WORDS];
+
+        if total > 0 {
+            clear_bit(&mut words, 0);
+        }
+        if total > 1 {
+            clear_bit(&mut words, 1);
+        }
+
+        let mut number: usize = 2;
+        let bound = isqrt(total as u64) as usize;
+        while number <= bound {
+            if bit_is_set(&words, number) {
+                let Some(mut composite) = number.checked_mul(number) else {
+                    break;
+                };
+
+                while composite < total {
+                    clear_bit(&mut words, composite);
+                    composite = match composite.checked_add(number) {
+                        Some(sum) => sum,
+                        None => break,
+                    };
+                }
+            }
+            number += 1;
+        }
+
+        Self { words }
+    }
+
+    /// Returns the number of numbers this sieve covers, `WORDS * 64`.
+    #[inline]
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn capacity(&self) -> usize {
+        WORDS * 64
+    }
+
+    /// Returns whether `i` is prime according to this sieve.
+    ///
+    /// Returns `false` for every `i >= `[`self.capacity()`](Self::capacity), the same way
+    /// [`sieve`](crate::sieve) would if it were extended with only non-prime entries past `N`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::BitSieve;
+    /// const SIEVE: BitSieve<1> = BitSieve::new_sieve();
+    /// assert!(SIEVE.is_set(2));
+    /// assert!(!SIEVE.is_set(1));
+    /// assert!(!SIEVE.is_set(1000)); // past `self.capacity()`
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn is_set(&self, i: usize) -> bool {
+        if i >= self.capacity() {
+            return false;
+        }
+        bit_is_set(&self.words, i)
+    }
+
+    /// Unpacks this sieve into a `[bool; N]`, for compatibility with APIs that take the output of
+    /// [`sieve`](crate::sieve) directly.
+    ///
+    /// Entries at indices `>= N` are simply not copied; entries at indices `>= self.capacity()`
+    /// are `false`, the same as [`is_set`](Self::is_set) would report for them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::BitSieve;
+    /// const SIEVE: BitSieve<1> = BitSieve::new_sieve(); // covers 0..64
+    /// const BOOLS: [bool; 10] = SIEVE.to_bool_array();
+    /// assert_eq!(BOOLS, const_primes::sieve::<10>());
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn to_bool_array<const N: usize>(&self) -> [bool; N] {
+        let mut out = [false; N];
+        let mut i = 0;
+        while i < N {
+            out[i] = self.is_set(i);
+            i += 1;
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::sieve;
+
+    #[test]
+    fn check_bit_sieve() {
+        const SIEVE: BitSieve<2> = BitSieve::new_sieve(); // covers 0..128
+        const BOOLS: [bool; 128] = SIEVE.to_bool_array();
+        const REFERENCE: [bool; 128] = sieve();
+        assert_eq!(BOOLS, REFERENCE);
+
+        assert_eq!(SIEVE.capacity(), 128);
+        assert!(!SIEVE.is_set(0));
+        assert!(!SIEVE.is_set(1));
+        assert!(SIEVE.is_set(2));
+        assert!(SIEVE.is_set(127));
+        assert!(!SIEVE.is_set(4));
+        assert!(!SIEVE.is_set(200)); // past capacity
+    }
+
+    #[test]
+    fn check_bit_sieve_small() {
+        const EMPTY: BitSieve<0> = BitSieve::new_sieve();
+        assert_eq!(EMPTY.capacity(), 0);
+        assert!(!EMPTY.is_set(0));
+        const EMPTY_BOOLS: [bool; 0] = EMPTY.to_bool_array();
+        assert_eq!(EMPTY_BOOLS, [] as [bool; 0]);
+    }
+}
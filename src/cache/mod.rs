@@ -2,14 +2,23 @@
 //! which functions as a cache of prime numbers for related computations.
 
 mod prime_factors;
+mod prime_gaps;
 mod primes_into_iter;
 mod primes_iter;
 
 pub use prime_factors::{PrimeFactorization, PrimeFactors};
+pub use prime_gaps::PrimeGaps;
 pub use primes_into_iter::PrimesIntoIter;
 pub use primes_iter::PrimesIter;
 
-use crate::{primes, Underlying};
+use core::cmp::Ordering;
+use core::fmt;
+
+use crate::{
+    factor::{checked_pow_u64, prime_in_factorial},
+    integer_math::mod_pow_u128,
+    primes, ArraySection, ArraySectionIntoIter, Underlying,
+};
 
 // region: Primes<N>
 
@@ -55,9 +64,85 @@ use crate::{primes, Underlying};
 )]
 #[cfg_attr(feature = "zerocopy", repr(transparent))]
 pub struct Primes<const N: usize>(
-    #[cfg_attr(feature = "serde", serde(with = "serde_arrays"))] [Underlying; N],
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "serde_arrays::serialize",
+            deserialize_with = "deserialize_validated_primes"
+        )
+    )]
+    [Underlying; N],
 );
 
+/// Deserializes `[Underlying; N]` and checks that it actually holds the first `N` primes, in
+/// increasing order and with no prime skipped, before handing it to [`Primes`].
+///
+/// This exists because a deserialized [`Primes`] is trusted by [`Primes::binary_search`] and
+/// every method built on it, so letting corrupt data through would silently break those
+/// invariants instead of failing loudly at the deserialization boundary.
+#[cfg(feature = "serde")]
+fn deserialize_validated_primes<'de, D, const N: usize>(
+    deserializer: D,
+) -> Result<[Underlying; N], D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error;
+
+    let candidate: [Underlying; N] = serde_arrays::deserialize(deserializer)?;
+
+    let mut i = 0;
+    while i < N {
+        let p = candidate[i];
+        if !crate::is_prime(p as u64) {
+            return Err(D::Error::custom(format_args!("{p} is not prime")));
+        }
+        if i == 0 {
+            if p != 2 {
+                return Err(D::Error::custom("the first prime must be 2"));
+            }
+        } else {
+            let previous = candidate[i - 1];
+            if p <= previous {
+                return Err(D::Error::custom("the primes are not strictly increasing"));
+            }
+            let mut candidate_between = previous + 1;
+            while candidate_between < p {
+                if crate::is_prime(candidate_between as u64) {
+                    return Err(D::Error::custom(format_args!(
+                        "prime {candidate_between} is missing between {previous} and {p}"
+                    )));
+                }
+                candidate_between += 1;
+            }
+        }
+        i += 1;
+    }
+
+    Ok(candidate)
+}
+
+/// Returns `C(a, b) mod p` for `0 <= b <= a < p` and `p` prime, computed from the multiplicative
+/// formula `C(a, b) = a! / (b! * (a - b)!)` by multiplying the numerator and the inverse of the
+/// denominator modulo `p`, found with Fermat's little theorem since `p` is prime.
+const fn small_binomial_mod(a: u64, b: u64, p: u64) -> u64 {
+    if b == 0 || b == a {
+        return 1;
+    }
+
+    let mut numerator: u64 = 1;
+    let mut denominator: u64 = 1;
+    let mut i = 0;
+    while i < b {
+        numerator = ((numerator as u128 * (a - i) as u128) % p as u128) as u64;
+        denominator = ((denominator as u128 * (i + 1) as u128) % p as u128) as u64;
+        i += 1;
+    }
+
+    let inverse_denominator = mod_pow_u128(denominator as u128, (p - 2) as u128, p as u128);
+    ((numerator as u128 * inverse_denominator) % p as u128) as u64
+}
+
 impl<const N: usize> Primes<N> {
     /// Generates a new instance that contains the first `N` primes.
     ///
@@ -135,6 +220,171 @@ impl<const N: usize> Primes<N> {
         }
     }
 
+    /// Returns whether `n` is one of the primes in `self`.
+    ///
+    /// Unlike [`is_prime`](Self::is_prime), this has no third "unknown" state: numbers larger than
+    /// the largest prime in `self` simply return `false`, the same as any other number not in the
+    /// cache.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// const PRIMES: Primes<100> = Primes::new();
+    /// assert!(PRIMES.contains(13));
+    /// assert!(!PRIMES.contains(42));
+    /// // 1000 is larger than 541, the largest prime in the cache, but `contains`
+    /// // just says "no", where `is_prime` would say "I don't know".
+    /// assert!(!PRIMES.contains(1000));
+    /// assert_eq!(PRIMES.is_prime(1000), None);
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn contains(&self, n: Underlying) -> bool {
+        self.binary_search(n).is_ok()
+    }
+
+    /// Returns whether `p` is a Sophie Germain prime, i.e. whether both `p` and `2p + 1` are prime,
+    /// if both can be decided from `self`.
+    ///
+    /// Returns [`None`] if `p` is larger than the largest prime in `self`, or if `p` is prime but
+    /// `2p + 1` overflows a `u32` or is larger than the largest prime in `self`.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// const CACHE: Primes<100> = Primes::new();
+    /// assert_eq!(CACHE.is_sophie_germain(2), Some(true)); // 2*2 + 1 = 5 is prime
+    /// assert_eq!(CACHE.is_sophie_germain(7), Some(false)); // 2*7 + 1 = 15 is not prime
+    /// assert_eq!(CACHE.is_sophie_germain(6), Some(false)); // 6 is not prime
+    /// assert_eq!(CACHE.is_sophie_germain(1_000), None); // larger than the cache
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn is_sophie_germain(&self, p: Underlying) -> Option<bool> {
+        match self.is_prime(p) {
+            Some(true) => match p.checked_mul(2) {
+                Some(doubled) => match doubled.checked_add(1) {
+                    Some(two_p_plus_one) => self.is_prime(two_p_plus_one),
+                    None => None,
+                },
+                None => None,
+            },
+            decided_or_unknown => decided_or_unknown,
+        }
+    }
+
+    /// Returns whether `p` is a safe prime, i.e. whether both `p` and `(p - 1) / 2` are prime,
+    /// if both can be decided from `self`.
+    ///
+    /// Returns [`None`] if `p` is larger than the largest prime in `self`.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// const CACHE: Primes<100> = Primes::new();
+    /// assert_eq!(CACHE.is_safe_prime(5), Some(true)); // (5 - 1) / 2 = 2 is prime
+    /// assert_eq!(CACHE.is_safe_prime(13), Some(false)); // (13 - 1) / 2 = 6 is not prime
+    /// assert_eq!(CACHE.is_safe_prime(6), Some(false)); // 6 is not prime
+    /// assert_eq!(CACHE.is_safe_prime(1_000), None); // larger than the cache
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn is_safe_prime(&self, p: Underlying) -> Option<bool> {
+        match self.is_prime(p) {
+            Some(true) => self.is_prime((p - 1) / 2),
+            decided_or_unknown => decided_or_unknown,
+        }
+    }
+
+    /// Returns the prime and exponent `(p, k)` such that `n == p^k`, if `n` is a prime power
+    /// and `p` is present in `self`.
+    ///
+    /// Returns [`None`] if `n` is smaller than 2, has more than one distinct prime factor,
+    /// or has a prime factor that is not present in the cache.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// const CACHE: Primes<3> = Primes::new(); // [2, 3, 5]
+    /// assert_eq!(CACHE.as_prime_power(8), Some((2, 3)));
+    /// assert_eq!(CACHE.as_prime_power(5), Some((5, 1)));
+    /// assert_eq!(CACHE.as_prime_power(12), None); // has two distinct prime factors
+    /// assert_eq!(CACHE.as_prime_power(1), None);
+    /// assert_eq!(CACHE.as_prime_power(49), None); // 7 is not present in the cache
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn as_prime_power(&self, n: Underlying) -> Option<(Underlying, u8)> {
+        if n < 2 {
+            return None;
+        }
+
+        let mut remainder = n;
+        let mut result: Option<(Underlying, u8)> = None;
+        let mut i = 0;
+        while let Some(&prime) = self.get(i) {
+            if remainder % prime == 0 {
+                if result.is_some() {
+                    return None;
+                }
+                let mut exponent = 0u8;
+                while remainder % prime == 0 {
+                    remainder /= prime;
+                    exponent += 1;
+                }
+                result = Some((prime, exponent));
+            }
+            if remainder == 1 {
+                break;
+            }
+            i += 1;
+        }
+
+        if remainder == 1 {
+            result
+        } else {
+            None
+        }
+    }
+
+    /// Returns how many times the prime `p` divides `n`, if `p` is present in `self`.
+    ///
+    /// This is narrower than [`as_prime_power`](Self::as_prime_power) or full factorization: it's
+    /// useful when the prime of interest is already known, for example the 2-adic valuation
+    /// `factor_multiplicity(n, 2)`.
+    ///
+    /// Returns [`None`] if `p` is not present in the cache, regardless of whether it actually
+    /// divides `n`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// const CACHE: Primes<3> = Primes::new(); // [2, 3, 5]
+    /// assert_eq!(CACHE.factor_multiplicity(40, 2), Some(3)); // 40 = 2^3 * 5
+    /// assert_eq!(CACHE.factor_multiplicity(40, 5), Some(1));
+    /// assert_eq!(CACHE.factor_multiplicity(40, 3), Some(0)); // 3 does not divide 40
+    /// assert_eq!(CACHE.factor_multiplicity(40, 7), None); // 7 is not present in the cache
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn factor_multiplicity(&self, n: Underlying, p: Underlying) -> Option<u8> {
+        if !matches!(self.is_prime(p), Some(true)) {
+            return None;
+        }
+
+        let mut remainder = n;
+        let mut exponent = 0u8;
+        while remainder != 0 && remainder % p == 0 {
+            remainder /= p;
+            exponent += 1;
+        }
+        Some(exponent)
+    }
+
     /// Returns the number of primes smaller than or equal to `n`, if it's smaller than or equal to the largest prime in `self`.
     ///
     /// Uses a binary search to count the primes.
@@ -157,6 +407,8 @@ impl<const N: usize> Primes<N> {
     #[must_use = "the method only returns a new value and does not modify `self`"]
     pub const fn prime_pi(&self, n: Underlying) -> Option<usize> {
         match self.binary_search(n) {
+            // `i` is a valid index into the `N`-length underlying array, so `i < N <= usize::MAX`
+            // and `i + 1` can never overflow.
             Ok(i) => Some(i + 1),
             Err(maybe_i) => {
                 if maybe_i < N {
@@ -168,6 +420,167 @@ impl<const N: usize> Primes<N> {
         }
     }
 
+    /// Returns the `k`-th (0-indexed) distinct prime factor of `n`, in increasing order.
+    ///
+    /// This is the `const` equivalent of calling [`nth`](Iterator::nth) on [`prime_factors`](Self::prime_factors),
+    /// for when only one specific factor is needed instead of the whole factorization.
+    ///
+    /// Returns [`None`] if `n` has fewer than `k + 1` distinct prime factors within `self`,
+    /// which includes the case where one of the factors up to the `k`-th is larger than every prime in `self`.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// // Contains the primes [2, 3, 5, 7, 11]
+    /// const CACHE: Primes<5> = Primes::new();
+    ///
+    /// // 60 = 2^2 * 3 * 5
+    /// const FIRST: Option<u32> = CACHE.kth_prime_factor(60, 0);
+    /// const SECOND: Option<u32> = CACHE.kth_prime_factor(60, 1);
+    /// const THIRD: Option<u32> = CACHE.kth_prime_factor(60, 2);
+    /// const FOURTH: Option<u32> = CACHE.kth_prime_factor(60, 3);
+    ///
+    /// assert_eq!(FIRST, Some(2));
+    /// assert_eq!(SECOND, Some(3));
+    /// assert_eq!(THIRD, Some(5));
+    /// assert_eq!(FOURTH, None);
+    /// ```
+    ///
+    /// A factor outside the cache makes every later index unreachable:
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// // Contains the primes [2, 3]
+    /// const CACHE: Primes<2> = Primes::new();
+    ///
+    /// // 2*3*7
+    /// assert_eq!(CACHE.kth_prime_factor(42, 1), Some(3));
+    /// assert_eq!(CACHE.kth_prime_factor(42, 2), None);
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn kth_prime_factor(&self, mut n: Underlying, k: usize) -> Option<Underlying> {
+        if n < 2 {
+            return None;
+        }
+
+        let mut i = 0;
+        let mut found = 0;
+        while let Some(&prime) = self.get(i) {
+            if n % prime == 0 {
+                if found == k {
+                    return Some(prime);
+                }
+                found += 1;
+                while n % prime == 0 {
+                    n /= prime;
+                }
+                if n == 1 {
+                    return None;
+                }
+            }
+            i += 1;
+        }
+
+        None
+    }
+
+    /// Returns the smallest prime factor of `n`, if it is known from `self`.
+    ///
+    /// A thin, more discoverable alias for [`kth_prime_factor`](Self::kth_prime_factor)`(n, 0)`,
+    /// for the common case of only wanting the smallest factor, such as in trial-division-style
+    /// problems.
+    ///
+    /// Returns [`None`] if `n < 2`, or if `n`'s smallest prime factor is larger than every prime
+    /// in `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// // Contains the primes [2, 3, 5, 7, 11, 13]
+    /// const CACHE: Primes<6> = Primes::new();
+    /// assert_eq!(CACHE.smallest_prime_factor(15), Some(3));
+    /// assert_eq!(CACHE.smallest_prime_factor(13), Some(13)); // 13 is itself prime
+    /// assert_eq!(CACHE.smallest_prime_factor(1), None);
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn smallest_prime_factor(&self, n: Underlying) -> Option<Underlying> {
+        self.kth_prime_factor(n, 0)
+    }
+
+    /// Returns the largest prime factor of `n`, if the full prime factorization of `n` is known
+    /// from `self`.
+    ///
+    /// `n < 2` has no prime factors; this returns `Ok(0)` for that case rather than treating it
+    /// as an error, since `0` can't be mistaken for an actual factor.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// // Contains the primes [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31]
+    /// const CACHE: Primes<11> = Primes::new();
+    /// // 13195 = 5 * 7 * 13 * 29
+    /// assert_eq!(CACHE.largest_prime_factor(13195), Ok(29));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If `n` contains prime factors that are not part of `self`, a [`Result::Err`] is returned
+    /// that contains a [`PartialLargestPrimeFactor`] with the largest factor found using only the
+    /// primes in `self`, as well as the product of the prime factors that are not included in
+    /// `self` (which may itself be prime, and thus the true largest prime factor of `n`):
+    ///
+    /// ```
+    /// # use const_primes::{Primes, cache::PartialLargestPrimeFactor};
+    /// // Contains the primes [2, 3, 5]
+    /// const CACHE: Primes<3> = Primes::new();
+    /// assert_eq!(
+    ///     CACHE.largest_prime_factor(2 * 37),
+    ///     Err(PartialLargestPrimeFactor {
+    ///         largest_prime_factor_using_known_primes: 2,
+    ///         product_of_unknown_prime_factors: 37,
+    ///     })
+    /// );
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn largest_prime_factor(
+        &self,
+        n: Underlying,
+    ) -> Result<Underlying, PartialLargestPrimeFactor> {
+        if n < 2 {
+            return Ok(0);
+        }
+
+        let mut remainder = n;
+        let mut largest = 0;
+        let mut i = 0;
+        while let Some(&prime) = self.get(i) {
+            if remainder % prime == 0 {
+                largest = prime;
+                while remainder % prime == 0 {
+                    remainder /= prime;
+                }
+            }
+            if remainder == 1 {
+                break;
+            }
+            i += 1;
+        }
+
+        if remainder == 1 {
+            Ok(largest)
+        } else {
+            Err(PartialLargestPrimeFactor {
+                largest_prime_factor_using_known_primes: largest,
+                product_of_unknown_prime_factors: remainder,
+            })
+        }
+    }
+
     /// Returns an iterator over the prime factors of the given number in increasing order as well as their
     /// multiplicities.
     ///
@@ -353,6 +766,8 @@ impl<const N: usize> Primes<N> {
         let mut left = 0;
         let mut right = size;
         while left < right {
+            // `left <= mid < right <= N`, and `N` is the length of the underlying array,
+            // so neither this addition nor the `right - left` below can overflow or underflow.
             let mid = left + size / 2;
             let candidate = self.0[mid];
             if candidate < target {
@@ -400,72 +815,73 @@ impl<const N: usize> Primes<N> {
         self.0.as_slice()
     }
 
-    /// Returns a borrowing iterator over the primes.
+    /// Converts `self` into an array of size `N` by widening each prime to a `u64`.
     ///
     /// # Example
     ///
-    /// Basic usage:
-    ///
     /// ```
     /// # use const_primes::Primes;
-    /// const PRIMES: Primes<10> = Primes::new();
-    ///
-    /// let mut primes = PRIMES.iter();
-    ///
-    /// assert_eq!(primes.nth(5), Some(&13));
-    /// assert_eq!(primes.next(), Some(&17));
-    /// assert_eq!(primes.as_slice(), &[19, 23, 29]);
+    /// const PRIMES: [u64; 5] = Primes::new().to_u64_array();
+    /// assert_eq!(PRIMES, [2, 3, 5, 7, 11]);
     /// ```
-    #[inline]
-    pub fn iter(&self) -> PrimesIter<'_> {
-        PrimesIter::new(IntoIterator::into_iter(&self.0))
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn to_u64_array(&self) -> [u64; N] {
+        let mut widened = [0u64; N];
+        let mut i = 0;
+        while i < N {
+            widened[i] = self.0[i] as u64;
+            i += 1;
+        }
+        widened
     }
 
-    // endregion: Conversions
-
-    /// Returns a reference to the element at the given index if it is within bounds.
+    /// Returns the sum of all the primes in `self`, widening each one to a `u64` before adding
+    /// to avoid overflow.
     ///
     /// # Example
     ///
-    /// Basic usage:
-    ///
     /// ```
     /// # use const_primes::Primes;
-    /// const PRIMES: Primes<5> = Primes::new();
-    /// const THIRD_PRIME: Option<&u32> = PRIMES.get(2);
-    /// assert_eq!(THIRD_PRIME, Some(&5));
+    /// const SUM: u64 = Primes::<6>::new().sum();
+    /// assert_eq!(SUM, 2 + 3 + 5 + 7 + 11 + 13);
     /// ```
-    #[inline]
     #[must_use = "the method only returns a new value and does not modify `self`"]
-    pub const fn get(&self, index: usize) -> Option<&Underlying> {
-        if index < N {
-            Some(&self.0[index])
-        } else {
-            None
+    pub const fn sum(&self) -> u64 {
+        let mut sum = 0u64;
+        let mut i = 0;
+        while i < N {
+            sum += self.0[i] as u64;
+            i += 1;
         }
+        sum
     }
 
-    /// Returns a reference to the last prime in `self`. This is also the largest prime in `self`.
+    /// Returns the sum of all the primes in `self` as a `u32`, wrapping on overflow.
     ///
-    /// # Example
+    /// Use [`sum`](Self::sum) instead unless you specifically want modular behavior: the sum of
+    /// the primes in a large enough cache overflows a `u32`.
     ///
-    /// Basic usage:
+    /// # Example
     ///
     /// ```
     /// # use const_primes::Primes;
-    /// const PRIMES: Primes<5> = Primes::new();
-    /// assert_eq!(PRIMES.last(), &11);
+    /// const SUM: u32 = Primes::<6>::new().sum_wrapping();
+    /// assert_eq!(SUM, 2 + 3 + 5 + 7 + 11 + 13);
     /// ```
-    #[inline]
     #[must_use = "the method only returns a new value and does not modify `self`"]
-    pub const fn last(&self) -> &Underlying {
-        match self.0.last() {
-            Some(l) => l,
-            None => panic!("unreachable: an empty `Primes<N>` can not be created"),
+    pub const fn sum_wrapping(&self) -> Underlying {
+        let mut sum: Underlying = 0;
+        let mut i = 0;
+        while i < N {
+            sum = sum.wrapping_add(self.0[i]);
+            i += 1;
         }
+        sum
     }
 
-    /// Returns the number of primes in `self`.
+    /// Returns a borrowing iterator over the gaps between consecutive primes in `self`.
+    ///
+    /// Yields `N - 1` values, since it's the difference between adjacent cached primes.
     ///
     /// # Example
     ///
@@ -473,35 +889,215 @@ impl<const N: usize> Primes<N> {
     ///
     /// ```
     /// # use const_primes::Primes;
-    /// const PRIMES: Primes<5> = Primes::new();
-    /// assert_eq!(PRIMES.len(), 5);
+    /// const PRIMES: Primes<6> = Primes::new(); // [2, 3, 5, 7, 11, 13]
+    /// let gaps: Vec<u32> = PRIMES.gaps().collect();
+    /// assert_eq!(gaps, [1, 2, 2, 4, 2]);
     /// ```
     #[inline]
-    #[must_use = "the method only returns a new value and does not modify `self`"]
-    // Can never be empty since we panic if the user tries to create an empty `Primes`.
-    #[allow(clippy::len_without_is_empty)]
-    pub const fn len(&self) -> usize {
-        N
+    pub fn gaps(&self) -> PrimeGaps<'_> {
+        PrimeGaps::new(self.0.windows(2))
     }
 
-    /// Returns the value of the Euler totient function of `n`:
-    /// the number of positive integers up to `n` that are relatively prime to it.
+    /// Returns a borrowing iterator over the primes.
     ///
     /// # Example
     ///
+    /// Basic usage:
+    ///
     /// ```
-    /// # use const_primes::{Primes, cache::PartialTotient};
-    /// const CACHE: Primes<3> = Primes::new();
-    /// const TOTIENT_OF_6: Result<u32, PartialTotient> = CACHE.totient(2*3);
+    /// # use const_primes::Primes;
+    /// const PRIMES: Primes<10> = Primes::new();
     ///
-    /// assert_eq!(TOTIENT_OF_6, Ok(2));
+    /// let mut primes = PRIMES.iter();
+    ///
+    /// assert_eq!(primes.nth(5), Some(&13));
+    /// assert_eq!(primes.next(), Some(&17));
+    /// assert_eq!(primes.as_slice(), &[19, 23, 29]);
     /// ```
+    /// Returns a borrowing iterator over the gaps between consecutive primes in `self`.
     ///
-    /// # Errors
+    /// Yields `N - 1` values, since it's the difference between adjacent cached primes.
     ///
-    /// The totient function is computed here as the product over all factors of the form p^(k-1)*(p-1) where
-    /// p is the primes in the prime factorization of `n` and k is their multiplicity.
-    /// If `n` contains prime factors that are not part of `self`, a [`Result::Err`] is returned
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// const PRIMES: Primes<6> = Primes::new(); // [2, 3, 5, 7, 11, 13]
+    /// let gaps: Vec<u32> = PRIMES.gaps().collect();
+    /// assert_eq!(gaps, [1, 2, 2, 4, 2]);
+    /// ```
+    #[inline]
+    pub fn gaps(&self) -> PrimeGaps<'_> {
+        PrimeGaps::new(self.0.windows(2))
+    }
+
+    #[inline]
+    pub fn iter(&self) -> PrimesIter<'_> {
+        PrimesIter::new(IntoIterator::into_iter(&self.0))
+    }
+
+    // endregion: Conversions
+
+    /// Returns a reference to the subslice corresponding to `range`, or `None` if `range` is out of bounds.
+    ///
+    /// Unlike indexing with [`Index`](core::ops::Index), this never panics.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// const PRIMES: Primes<10> = Primes::new();
+    /// const MIDDLE: Option<&[u32]> = PRIMES.get_range(2..5);
+    /// const OUT_OF_BOUNDS: Option<&[u32]> = PRIMES.get_range(5..1000);
+    ///
+    /// assert_eq!(MIDDLE, Some([5, 7, 11].as_slice()));
+    /// assert_eq!(OUT_OF_BOUNDS, None);
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn get_range(&self, range: core::ops::Range<usize>) -> Option<&[Underlying]> {
+        if range.start > range.end || range.end > N {
+            return None;
+        }
+        let (_, tail) = self.0.split_at(range.start);
+        let (middle, _) = tail.split_at(range.end - range.start);
+        Some(middle)
+    }
+
+    /// Returns a reference to the element at the given index if it is within bounds.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// const PRIMES: Primes<5> = Primes::new();
+    /// const THIRD_PRIME: Option<&u32> = PRIMES.get(2);
+    /// assert_eq!(THIRD_PRIME, Some(&5));
+    /// ```
+    #[inline]
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn get(&self, index: usize) -> Option<&Underlying> {
+        if index < N {
+            Some(&self.0[index])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the `n`-th prime, 1-indexed, i.e. `nth_prime(1)` is `2`.
+    ///
+    /// Returns `None` if `n` is `0` or if the `n`-th prime is not in `self`.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// const PRIMES: Primes<5> = Primes::new();
+    /// const FIRST: Option<u32> = PRIMES.nth_prime(1);
+    /// const THIRD: Option<u32> = PRIMES.nth_prime(3);
+    /// const ZEROTH: Option<u32> = PRIMES.nth_prime(0);
+    /// const OUT_OF_BOUNDS: Option<u32> = PRIMES.nth_prime(6);
+    /// assert_eq!(FIRST, Some(2));
+    /// assert_eq!(THIRD, Some(5));
+    /// assert_eq!(ZEROTH, None);
+    /// assert_eq!(OUT_OF_BOUNDS, None);
+    /// ```
+    #[inline]
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn nth_prime(&self, n: usize) -> Option<Underlying> {
+        if n == 0 {
+            return None;
+        }
+        match self.get(n - 1) {
+            Some(p) => Some(*p),
+            None => None,
+        }
+    }
+
+    /// Returns a reference to the last prime in `self`. This is also the largest prime in `self`.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// const PRIMES: Primes<5> = Primes::new();
+    /// assert_eq!(PRIMES.last(), &11);
+    /// ```
+    #[inline]
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn last(&self) -> &Underlying {
+        match self.0.last() {
+            Some(l) => l,
+            None => panic!("unreachable: an empty `Primes<N>` can not be created"),
+        }
+    }
+
+    /// Returns the largest number whose primality can be decided by trial division against the
+    /// primes in `self`, i.e. `last^2`.
+    ///
+    /// This makes the "valid up to last²" invariant used throughout this module's trial-division
+    /// based methods explicit, so it can be asserted against before calling into other, more
+    /// expensive primality tests.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// const PRIMES: Primes<5> = Primes::new(); // largest prime is 11
+    /// assert_eq!(PRIMES.max_trial_divisible(), 121);
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn max_trial_divisible(&self) -> u64 {
+        let last = *self.last() as u64;
+        last * last
+    }
+
+    /// Returns the number of primes in `self`.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// const PRIMES: Primes<5> = Primes::new();
+    /// assert_eq!(PRIMES.len(), 5);
+    /// ```
+    #[inline]
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    // Can never be empty since we panic if the user tries to create an empty `Primes`.
+    #[allow(clippy::len_without_is_empty)]
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns the value of the Euler totient function of `n`:
+    /// the number of positive integers up to `n` that are relatively prime to it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::{Primes, cache::PartialTotient};
+    /// const CACHE: Primes<3> = Primes::new();
+    /// const TOTIENT_OF_6: Result<u32, PartialTotient> = CACHE.totient(2*3);
+    ///
+    /// assert_eq!(TOTIENT_OF_6, Ok(2));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The totient function is computed here as the product over all factors of the form p^(k-1)*(p-1) where
+    /// p is the primes in the prime factorization of `n` and k is their multiplicity.
+    /// If `n` contains prime factors that are not part of `self`, a [`Result::Err`] is returned
     /// that contains a [`PartialTotient`] struct that contains the result from using only the primes in `self`,
     /// as well as the product of the prime factors that are not included in `self`.
     ///
@@ -531,47 +1127,1609 @@ impl<const N: usize> Primes<N> {
             return Ok(0);
         }
 
-        let mut i = 0;
-        let mut ans = 1;
-        while let Some(&prime) = self.get(i) {
-            let mut count = 0;
-            while n % prime == 0 {
-                n /= prime;
-                count += 1;
-            }
+        let mut i = 0;
+        let mut ans = 1;
+        while let Some(&prime) = self.get(i) {
+            let mut count = 0;
+            while n % prime == 0 {
+                n /= prime;
+                count += 1;
+            }
+
+            if count > 0 {
+                ans *= prime.pow(count - 1) * (prime - 1);
+            }
+
+            if n == 1 {
+                break;
+            }
+            i += 1;
+        }
+
+        if n == 1 {
+            Ok(ans)
+        } else {
+            Err(PartialTotient {
+                totient_using_known_primes: ans,
+                product_of_unknown_prime_factors: n,
+            })
+        }
+    }
+
+    /// Returns the number of terms in the Farey sequence of order `n`: the fully reduced
+    /// fractions between 0 and 1 (inclusive) whose denominator is at most `n`.
+    ///
+    /// Computed as `1 + sum_{k=1}^{n} totient(k)`, via repeated calls to [`totient`](Self::totient).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// const CACHE: Primes<3> = Primes::new(); // [2, 3, 5]
+    /// // The Farey sequence of order 5 is 0/1, 1/5, 1/4, 1/3, 2/5, 1/2, 3/5, 2/3, 3/4, 4/5, 1/1.
+    /// assert_eq!(CACHE.farey_length(5), Ok(11));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PartialFareyLength`] if some `k <= n` needs a prime factor that is not part of
+    /// `self`, containing the running sum over the `k` that succeeded and the partial result
+    /// from [`totient`](Self::totient) for the `k` that didn't.
+    ///
+    /// ```
+    /// # use const_primes::{Primes, cache::PartialFareyLength};
+    /// // Contains the primes [2, 3, 5]
+    /// const CACHE: Primes<3> = Primes::new();
+    /// assert_eq!(
+    ///     CACHE.farey_length(7),
+    ///     Err(PartialFareyLength {
+    ///         // 1 + totient(1) + ... + totient(6), plus the partial result for 7
+    ///         // (whose only factor, itself, isn't in the cache, so it contributes 1): 13 + 1 = 14
+    ///         farey_length_using_known_primes: 14,
+    ///         product_of_unknown_prime_factors: 7,
+    ///     })
+    /// );
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn farey_length(&self, n: u32) -> Result<u64, PartialFareyLength> {
+        let mut sum: u64 = 1;
+        let mut k = 1;
+        while k <= n {
+            match self.totient(k) {
+                Ok(t) => sum += t as u64,
+                Err(partial) => {
+                    return Err(PartialFareyLength {
+                        farey_length_using_known_primes: sum
+                            + partial.totient_using_known_primes as u64,
+                        product_of_unknown_prime_factors: partial.product_of_unknown_prime_factors,
+                    });
+                }
+            }
+            k += 1;
+        }
+        Ok(sum)
+    }
+
+    /// Returns τ(n), the number of positive divisors of `n`, if the full prime factorization of
+    /// `n` is known from `self`.
+    ///
+    /// Computed as the product of `multiplicity + 1` over every prime factor in `n`'s
+    /// factorization, via the same trial-division loop over the cached primes as
+    /// [`totient`](Self::totient).
+    ///
+    /// `n == 0` has no well-defined divisor count. Like [`totient`](Self::totient)'s `n == 0`
+    /// case, this returns `Ok(0)` rather than treating it as an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// const CACHE: Primes<3> = Primes::new(); // [2, 3, 5]
+    /// assert_eq!(CACHE.count_divisors(1), Ok(1));
+    /// assert_eq!(CACHE.count_divisors(6), Ok(4)); // 1, 2, 3, 6
+    /// assert_eq!(CACHE.count_divisors(12), Ok(6)); // 12 = 2^2 * 3, divisors 1,2,3,4,6,12
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If `n` contains prime factors that are not part of `self`, a [`Result::Err`] is returned
+    /// that contains a [`PartialDivisorCount`] with the result from using only the primes in
+    /// `self`, as well as the product of the prime factors that are not included in `self`:
+    ///
+    /// ```
+    /// # use const_primes::{Primes, cache::PartialDivisorCount};
+    /// // Contains the primes [2, 3, 5]
+    /// const CACHE: Primes<3> = Primes::new();
+    /// assert_eq!(
+    ///     CACHE.count_divisors(2 * 7),
+    ///     Err(PartialDivisorCount {
+    ///         divisor_count_using_known_primes: 2,
+    ///         product_of_unknown_prime_factors: 7,
+    ///     })
+    /// );
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn count_divisors(
+        &self,
+        mut n: Underlying,
+    ) -> Result<Underlying, PartialDivisorCount> {
+        if n == 0 {
+            return Ok(0);
+        }
+
+        let mut i = 0;
+        let mut ans = 1;
+        while let Some(&prime) = self.get(i) {
+            let mut count = 0;
+            while n % prime == 0 {
+                n /= prime;
+                count += 1;
+            }
+
+            if count > 0 {
+                ans *= count + 1;
+            }
+
+            if n == 1 {
+                break;
+            }
+            i += 1;
+        }
+
+        if n == 1 {
+            Ok(ans)
+        } else {
+            Err(PartialDivisorCount {
+                divisor_count_using_known_primes: ans,
+                product_of_unknown_prime_factors: n,
+            })
+        }
+    }
+
+    /// Returns σ(n), the sum of the positive divisors of `n`, if the full prime factorization of
+    /// `n` is known from `self`.
+    ///
+    /// Computed from the prime factorization of `n` as the product, over every prime power `p^e`
+    /// that divides `n`, of `1 + p + p^2 + ... + p^e`, rather than by enumerating the divisors
+    /// themselves.
+    ///
+    /// Returns [`None`] if `n` is 0, has a prime factor that is not present in the cache, or if
+    /// the result does not fit in [`Underlying`]. Use [`sum_divisors`](Self::sum_divisors) if you
+    /// need the wider, non-overflowing result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// const CACHE: Primes<3> = Primes::new(); // [2, 3, 5]
+    /// assert_eq!(CACHE.sum_of_divisors(1), Some(1));
+    /// assert_eq!(CACHE.sum_of_divisors(6), Some(12)); // 1 + 2 + 3 + 6
+    /// assert_eq!(CACHE.sum_of_divisors(2 * 7), None); // 7 is not present in the cache
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn sum_of_divisors(&self, n: Underlying) -> Option<Underlying> {
+        if n == 0 {
+            return None;
+        }
+        if n == 1 {
+            return Some(1);
+        }
+
+        let mut remainder = n;
+        // Widened to `u64` since `sigma` can exceed `Underlying::MAX` well before `n` does.
+        let mut sigma: u64 = 1;
+        let mut i = 0;
+        while let Some(&prime) = self.get(i) {
+            if remainder % prime == 0 {
+                let mut prime_power: u64 = 1;
+                let mut term: u64 = 1;
+                while remainder % prime == 0 {
+                    remainder /= prime;
+                    prime_power *= prime as u64;
+                    term += prime_power;
+                }
+                sigma *= term;
+            }
+            if remainder == 1 {
+                break;
+            }
+            i += 1;
+        }
+
+        if remainder == 1 {
+            if sigma <= Underlying::MAX as u64 {
+                Some(sigma as Underlying)
+            } else {
+                None
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Returns σ(n), the sum of the positive divisors of `n`, if the full prime factorization of
+    /// `n` is known from `self`.
+    ///
+    /// Computed the same way as [`sum_of_divisors`](Self::sum_of_divisors), but widening the
+    /// result to a `u64` (since σ can grow past a `u32` quickly) and, on failure, reporting a
+    /// partial result analogous to [`totient`](Self::totient) instead of collapsing every
+    /// failure to [`None`].
+    ///
+    /// `n == 0` has no well-defined divisor sum. Like [`totient`](Self::totient)'s `n == 0`
+    /// case, this returns `Ok(0)` rather than treating it as an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// const CACHE: Primes<11> = Primes::new(); // [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31]
+    /// assert_eq!(CACHE.sum_divisors(6), Ok(12)); // 1 + 2 + 3 + 6
+    /// assert_eq!(CACHE.sum_divisors(28), Ok(56)); // 1 + 2 + 4 + 7 + 14 + 28
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If `n` contains prime factors that are not part of `self`, a [`Result::Err`] is returned
+    /// that contains a [`PartialSumOfDivisors`] with the result from using only the primes in
+    /// `self`, as well as the product of the prime factors that are not included in `self`:
+    ///
+    /// ```
+    /// # use const_primes::{Primes, cache::PartialSumOfDivisors};
+    /// // Contains the primes [2, 3, 5]
+    /// const CACHE: Primes<3> = Primes::new();
+    /// assert_eq!(
+    ///     CACHE.sum_divisors(2 * 7),
+    ///     Err(PartialSumOfDivisors {
+    ///         sum_of_divisors_using_known_primes: 3, // 1 + 2
+    ///         product_of_unknown_prime_factors: 7,
+    ///     })
+    /// );
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn sum_divisors(&self, n: Underlying) -> Result<u64, PartialSumOfDivisors> {
+        if n == 0 {
+            return Ok(0);
+        }
+        if n == 1 {
+            return Ok(1);
+        }
+
+        let mut remainder = n;
+        let mut sigma: u64 = 1;
+        let mut i = 0;
+        while let Some(&prime) = self.get(i) {
+            if remainder % prime == 0 {
+                let mut prime_power: u64 = 1;
+                let mut term: u64 = 1;
+                while remainder % prime == 0 {
+                    remainder /= prime;
+                    prime_power *= prime as u64;
+                    term += prime_power;
+                }
+                sigma *= term;
+            }
+            if remainder == 1 {
+                break;
+            }
+            i += 1;
+        }
+
+        if remainder == 1 {
+            Ok(sigma)
+        } else {
+            Err(PartialSumOfDivisors {
+                sum_of_divisors_using_known_primes: sigma,
+                product_of_unknown_prime_factors: remainder,
+            })
+        }
+    }
+
+    /// Returns whether `n` is squarefree, i.e. not divisible by any perfect square greater than
+    /// 1, if the full prime factorization of `n` is known from `self`.
+    ///
+    /// This is the same factor-by-factor check that [`mobius`](Self::mobius) uses to detect a
+    /// squared prime factor, just without also tracking the sign of the result.
+    ///
+    /// Returns [`None`] if `n` is 0, or has a prime factor that is not present in the cache.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// const CACHE: Primes<3> = Primes::new(); // [2, 3, 5]
+    /// assert_eq!(CACHE.is_squarefree(1), Some(true));
+    /// assert_eq!(CACHE.is_squarefree(2 * 3 * 5), Some(true));
+    /// assert_eq!(CACHE.is_squarefree(12), Some(false)); // 12 = 2^2 * 3
+    /// assert_eq!(CACHE.is_squarefree(2 * 7), None); // 7 is not present in the cache
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn is_squarefree(&self, n: Underlying) -> Option<bool> {
+        if n == 0 {
+            return None;
+        }
+        if n == 1 {
+            return Some(true);
+        }
+
+        let mut remainder = n;
+        let mut i = 0;
+        while let Some(&prime) = self.get(i) {
+            if remainder % prime == 0 {
+                remainder /= prime;
+                if remainder % prime == 0 {
+                    return Some(false);
+                }
+            }
+            if remainder == 1 {
+                break;
+            }
+            i += 1;
+        }
+
+        if remainder == 1 {
+            Some(true)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the radical of `n`, the product of its distinct prime factors, if the full prime
+    /// factorization of `n` is known from `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// const CACHE: Primes<3> = Primes::new(); // [2, 3, 5]
+    /// assert_eq!(CACHE.radical(1), Ok(1));
+    /// assert_eq!(CACHE.radical(12), Ok(6)); // 12 = 2^2 * 3, radical 2 * 3
+    /// assert_eq!(CACHE.radical(2 * 2 * 3 * 3 * 5), Ok(30));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If `n` contains prime factors that are not part of `self`, a [`Result::Err`] is returned
+    /// that contains a [`PartialRadical`] with the result from using only the primes in `self`,
+    /// as well as the product of the prime factors that are not included in `self`:
+    ///
+    /// ```
+    /// # use const_primes::{Primes, cache::PartialRadical};
+    /// // Contains the primes [2, 3, 5]
+    /// const CACHE: Primes<3> = Primes::new();
+    /// assert_eq!(
+    ///     CACHE.radical(2 * 7),
+    ///     Err(PartialRadical {
+    ///         radical_using_known_primes: 2,
+    ///         product_of_unknown_prime_factors: 7,
+    ///     })
+    /// );
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn radical(&self, mut n: Underlying) -> Result<Underlying, PartialRadical> {
+        if n == 0 {
+            return Ok(0);
+        }
+
+        let mut i = 0;
+        let mut rad: Underlying = 1;
+        while let Some(&prime) = self.get(i) {
+            if n % prime == 0 {
+                rad *= prime;
+                while n % prime == 0 {
+                    n /= prime;
+                }
+            }
+            if n == 1 {
+                break;
+            }
+            i += 1;
+        }
+
+        if n == 1 {
+            Ok(rad)
+        } else {
+            Err(PartialRadical {
+                radical_using_known_primes: rad,
+                product_of_unknown_prime_factors: n,
+            })
+        }
+    }
+
+    /// Returns the value of the Möbius function μ(n), if the full prime factorization of `n`
+    /// is known from `self`.
+    ///
+    /// μ(n) is 0 if `n` has a squared prime factor, 1 if `n` is squarefree with an even number
+    /// of prime factors, and -1 if `n` is squarefree with an odd number of prime factors.
+    ///
+    /// Returns [`None`] if `n` is 0, or has a prime factor that is not present in the cache.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// const CACHE: Primes<3> = Primes::new(); // [2, 3, 5]
+    /// assert_eq!(CACHE.mobius(1), Some(1));
+    /// assert_eq!(CACHE.mobius(2 * 3), Some(1)); // squarefree, two factors
+    /// assert_eq!(CACHE.mobius(2 * 3 * 5), Some(-1)); // squarefree, three factors
+    /// assert_eq!(CACHE.mobius(2 * 2), Some(0)); // has a squared factor
+    /// assert_eq!(CACHE.mobius(2 * 7), None); // 7 is not present in the cache
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn mobius(&self, n: Underlying) -> Option<i8> {
+        if n == 0 {
+            return None;
+        }
+        if n == 1 {
+            return Some(1);
+        }
+
+        let mut remainder = n;
+        let mut sign: i8 = 1;
+        let mut i = 0;
+        while let Some(&prime) = self.get(i) {
+            if remainder % prime == 0 {
+                remainder /= prime;
+                if remainder % prime == 0 {
+                    return Some(0);
+                }
+                sign = -sign;
+            }
+            if remainder == 1 {
+                break;
+            }
+            i += 1;
+        }
+
+        if remainder == 1 {
+            Some(sign)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value of the Mertens function M(x), the sum of [`mobius`](Self::mobius)(k)
+    /// for k from 1 to `x` inclusive, if every one of those values is known from `self`.
+    ///
+    /// Returns [`None`] if any `k <= x` has a prime factor that is not present in the cache,
+    /// since the sum can then not be completed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// const CACHE: Primes<3> = Primes::new(); // [2, 3, 5]
+    /// assert_eq!(CACHE.mertens(6), Some(-1));
+    /// assert_eq!(CACHE.mertens(7), None); // mobius(7) needs a prime outside the cache
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn mertens(&self, x: Underlying) -> Option<i32> {
+        let mut sum: i32 = 0;
+        let mut k = 1;
+        while k <= x {
+            match self.mobius(k) {
+                Some(mu) => sum += mu as i32,
+                None => return None,
+            }
+            k += 1;
+        }
+        Some(sum)
+    }
+
+    /// Returns the value of the Liouville function λ(n) = (-1)^Ω(n), where Ω(n) is the number of
+    /// prime factors of `n` counted with multiplicity, if the full prime factorization of `n` is
+    /// known from `self`.
+    ///
+    /// Unlike [`mobius`](Self::mobius), which is 0 as soon as any prime factor appears more than
+    /// once, this counts every occurrence, so it doesn't need `n` to be squarefree to
+    /// distinguish the two possible signs.
+    ///
+    /// Returns [`None`] if `n` is 0, or has a prime factor that is not present in the cache.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// const CACHE: Primes<3> = Primes::new(); // [2, 3, 5]
+    /// assert_eq!(CACHE.liouville(1), Some(1));
+    /// assert_eq!(CACHE.liouville(2 * 3), Some(1)); // two prime factors
+    /// assert_eq!(CACHE.liouville(2 * 2 * 3), Some(-1)); // three prime factors, with multiplicity
+    /// assert_eq!(CACHE.liouville(2 * 7), None); // 7 is not present in the cache
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn liouville(&self, n: Underlying) -> Option<i8> {
+        if n == 0 {
+            return None;
+        }
+        if n == 1 {
+            return Some(1);
+        }
+
+        let mut remainder = n;
+        let mut sign: i8 = 1;
+        let mut i = 0;
+        while let Some(&prime) = self.get(i) {
+            while remainder % prime == 0 {
+                remainder /= prime;
+                sign = -sign;
+            }
+            if remainder == 1 {
+                break;
+            }
+            i += 1;
+        }
+
+        if remainder == 1 {
+            Some(sign)
+        } else {
+            None
+        }
+    }
+
+    /// Returns sopf(n), the sum of the *distinct* primes dividing `n`, if the full prime
+    /// factorization of `n` is known from `self`.
+    ///
+    /// Returns [`None`] if `n` is 0, or has a prime factor that is not present in the cache.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// const CACHE: Primes<3> = Primes::new(); // [2, 3, 5]
+    /// assert_eq!(CACHE.sum_of_prime_factors(1), Some(0));
+    /// assert_eq!(CACHE.sum_of_prime_factors(18), Some(5)); // 18 = 2 * 3^2, 2 + 3 = 5
+    /// assert_eq!(CACHE.sum_of_prime_factors(2 * 7), None); // 7 is not present in the cache
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn sum_of_prime_factors(&self, n: Underlying) -> Option<Underlying> {
+        if n == 0 {
+            return None;
+        }
+        if n == 1 {
+            return Some(0);
+        }
+
+        let mut remainder = n;
+        let mut sum: Underlying = 0;
+        let mut i = 0;
+        while let Some(&prime) = self.get(i) {
+            if remainder % prime == 0 {
+                sum += prime;
+                while remainder % prime == 0 {
+                    remainder /= prime;
+                }
+            }
+            if remainder == 1 {
+                break;
+            }
+            i += 1;
+        }
+
+        if remainder == 1 {
+            Some(sum)
+        } else {
+            None
+        }
+    }
+
+    /// Returns sopfr(n), the sum of the primes dividing `n` *with multiplicity* (the integer
+    /// logarithm), if the full prime factorization of `n` is known from `self`.
+    ///
+    /// Used in the hunt for [Ruth-Aaron pairs](https://en.wikipedia.org/wiki/Ruth%E2%80%93Aaron_pair):
+    /// consecutive integers whose sopfr values are equal.
+    ///
+    /// Returns [`None`] if `n` is 0, or has a prime factor that is not present in the cache.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// const CACHE: Primes<3> = Primes::new(); // [2, 3, 5]
+    /// assert_eq!(CACHE.sum_of_prime_factors_with_multiplicity(1), Some(0));
+    /// assert_eq!(CACHE.sum_of_prime_factors_with_multiplicity(18), Some(8)); // 18 = 2 * 3 * 3
+    /// // 714 = 2 * 3 * 7 * 17 and 715 = 5 * 11 * 13 are a Ruth-Aaron pair: both sum to 29.
+    /// const CACHE2: Primes<6> = Primes::new(); // [2, 3, 5, 7, 11, 13]
+    /// assert_eq!(
+    ///     CACHE2.sum_of_prime_factors_with_multiplicity(714),
+    ///     None // 17 is not present in the cache
+    /// );
+    /// assert_eq!(CACHE2.sum_of_prime_factors_with_multiplicity(715), Some(29));
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn sum_of_prime_factors_with_multiplicity(
+        &self,
+        n: Underlying,
+    ) -> Option<Underlying> {
+        if n == 0 {
+            return None;
+        }
+        if n == 1 {
+            return Some(0);
+        }
+
+        let mut remainder = n;
+        let mut sum: Underlying = 0;
+        let mut i = 0;
+        while let Some(&prime) = self.get(i) {
+            while remainder % prime == 0 {
+                remainder /= prime;
+                sum += prime;
+            }
+            if remainder == 1 {
+                break;
+            }
+            i += 1;
+        }
+
+        if remainder == 1 {
+            Some(sum)
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether `n` and `n + 1` are a [Ruth-Aaron pair](https://en.wikipedia.org/wiki/Ruth%E2%80%93Aaron_pair),
+    /// i.e. whether [`sum_of_prime_factors_with_multiplicity`](Self::sum_of_prime_factors_with_multiplicity)
+    /// is equal for both.
+    ///
+    /// Returns [`None`] if `n` or `n + 1` is 0, or has a prime factor that is not present in the cache.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// const CACHE: Primes<11> = Primes::new(); // [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31]
+    /// assert_eq!(CACHE.is_ruth_aaron_pair(5), Some(true)); // sopfr(5) = 5, sopfr(6) = 2 + 3 = 5
+    /// assert_eq!(CACHE.is_ruth_aaron_pair(77), Some(true)); // sopfr(77) = 7 + 11 = 18, sopfr(78) = 2 + 3 + 13 = 18
+    /// assert_eq!(CACHE.is_ruth_aaron_pair(9), Some(false)); // sopfr(9) = 3 + 3 = 6, sopfr(10) = 2 + 5 = 7
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn is_ruth_aaron_pair(&self, n: Underlying) -> Option<bool> {
+        let Some(sopfr_n) = self.sum_of_prime_factors_with_multiplicity(n) else {
+            return None;
+        };
+        let Some(sopfr_n_plus_1) = self.sum_of_prime_factors_with_multiplicity(n + 1) else {
+            return None;
+        };
+        Some(sopfr_n == sopfr_n_plus_1)
+    }
+
+    /// Returns whether `n` is a [perfect number](https://en.wikipedia.org/wiki/Perfect_number),
+    /// i.e. whether it equals the sum of its own proper divisors, `σ(n) == 2n`.
+    ///
+    /// Built on [`sum_of_divisors`](Self::sum_of_divisors).
+    ///
+    /// Returns [`None`] if `n` is 0, or has a prime factor that is not present in the cache.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// const CACHE: Primes<11> = Primes::new(); // [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31]
+    /// assert_eq!(CACHE.is_perfect(6), Some(true)); // 1 + 2 + 3 = 6
+    /// assert_eq!(CACHE.is_perfect(28), Some(true)); // 1 + 2 + 4 + 7 + 14 = 28
+    /// assert_eq!(CACHE.is_perfect(496), Some(true));
+    /// assert_eq!(CACHE.is_perfect(12), Some(false));
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn is_perfect(&self, n: Underlying) -> Option<bool> {
+        match self.sum_of_divisors(n) {
+            Some(sigma) => Some(sigma == 2 * n),
+            None => None,
+        }
+    }
+
+    /// Returns the aliquot sum of `n`, σ(n) - n: the sum of the *proper* divisors of `n`.
+    ///
+    /// Built on [`sum_of_divisors`](Self::sum_of_divisors).
+    ///
+    /// Returns [`None`] if `n` is 0, or has a prime factor that is not present in the cache.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// const CACHE: Primes<3> = Primes::new(); // [2, 3, 5]
+    /// assert_eq!(CACHE.aliquot_sum(6), Some(6)); // 1 + 2 + 3
+    /// assert_eq!(CACHE.aliquot_sum(12), Some(16)); // 1 + 2 + 3 + 4 + 6
+    /// assert_eq!(CACHE.aliquot_sum(2 * 7), None); // 7 is not present in the cache
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn aliquot_sum(&self, n: Underlying) -> Option<Underlying> {
+        match self.sum_of_divisors(n) {
+            Some(sigma) => Some(sigma - n),
+            None => None,
+        }
+    }
+
+    /// Classifies `n` as deficient, perfect, or abundant by comparing its
+    /// [`aliquot_sum`](Self::aliquot_sum) to `n` itself.
+    ///
+    /// Returns [`Ordering::Less`] if `n` is deficient (aliquot sum < `n`),
+    /// [`Ordering::Equal`] if `n` is [perfect](Self::is_perfect) (aliquot sum == `n`), and
+    /// [`Ordering::Greater`] if `n` is abundant (aliquot sum > `n`).
+    ///
+    /// Returns [`None`] if `n` is 0, or has a prime factor that is not present in the cache.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// use core::cmp::Ordering;
+    /// const CACHE: Primes<3> = Primes::new(); // [2, 3, 5]
+    /// assert_eq!(CACHE.abundance_class(8), Some(Ordering::Less)); // 1 + 2 + 4 = 7 < 8
+    /// assert_eq!(CACHE.abundance_class(6), Some(Ordering::Equal)); // 1 + 2 + 3 = 6
+    /// assert_eq!(CACHE.abundance_class(12), Some(Ordering::Greater)); // 1+2+3+4+6 = 16 > 12
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn abundance_class(&self, n: Underlying) -> Option<Ordering> {
+        match self.aliquot_sum(n) {
+            Some(aliquot) => Some(if aliquot < n {
+                Ordering::Less
+            } else if aliquot > n {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }),
+            None => None,
+        }
+    }
+
+    /// Returns the value of the Jordan totient function `J_k(n)`, generalizing the Euler totient:
+    /// `n^k * prod_{p | n} (1 - p^-k)` over the distinct prime factors `p` of `n`.
+    ///
+    /// [`totient`](Self::totient) is the special case `J_1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::{Primes, cache::PartialJordanTotient};
+    /// const CACHE: Primes<3> = Primes::new(); // [2, 3, 5]
+    /// // J_1 is the ordinary totient.
+    /// assert_eq!(CACHE.jordan_totient(2 * 3, 1), Ok(2));
+    /// // J_2(6) = 6^2 * (1 - 1/2^2) * (1 - 1/3^2) = 24.
+    /// assert_eq!(CACHE.jordan_totient(2 * 3, 2), Ok(24));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Computed as the product over factors of the form `p^(k*(e - 1)) * (p^k - 1)`, where `p` is
+    /// a prime factor of `n` with multiplicity `e`. If `n` contains prime factors that are not
+    /// part of `self`, a [`JordanTotientError::Partial`] is returned with the product over the
+    /// known primes and the product of the unknown prime factors, mirroring [`PartialTotient`].
+    /// If the exact result would overflow a [`u64`] (`k` grows the result far faster than
+    /// [`totient`](Self::totient) does), [`JordanTotientError::Overflow`] is returned instead of
+    /// panicking.
+    ///
+    /// ```
+    /// # use const_primes::{Primes, cache::{JordanTotientError, PartialJordanTotient}};
+    /// // Contains the primes [2, 3, 5]
+    /// const CACHE: Primes<3> = Primes::new();
+    /// assert_eq!(
+    ///     CACHE.jordan_totient(2 * 7, 1),
+    ///     Err(JordanTotientError::Partial(PartialJordanTotient {
+    ///         jordan_totient_using_known_primes: 1,
+    ///         product_of_unknown_prime_factors: 7,
+    ///     }))
+    /// );
+    /// assert_eq!(CACHE.jordan_totient(2 * 3, 100), Err(JordanTotientError::Overflow));
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn jordan_totient(
+        &self,
+        mut n: Underlying,
+        k: u32,
+    ) -> Result<u64, JordanTotientError> {
+        if n == 0 {
+            return Ok(0);
+        }
+        if n == 1 {
+            return Ok(1);
+        }
+
+        let mut i = 0;
+        let mut ans: u64 = 1;
+        while let Some(&prime) = self.get(i) {
+            let mut count = 0u32;
+            while n % prime == 0 {
+                n /= prime;
+                count += 1;
+            }
+
+            if count > 0 {
+                let p = prime as u64;
+                let Some(exponent) = k.checked_mul(count - 1) else {
+                    return Err(JordanTotientError::Overflow);
+                };
+                let Some(p_pow_exponent) = checked_pow_u64(p, exponent) else {
+                    return Err(JordanTotientError::Overflow);
+                };
+                let Some(p_pow_k) = checked_pow_u64(p, k) else {
+                    return Err(JordanTotientError::Overflow);
+                };
+                // `p >= 2`, so `p_pow_k >= 1` and this never underflows.
+                let factor = p_pow_k - 1;
+                let Some(term) = p_pow_exponent.checked_mul(factor) else {
+                    return Err(JordanTotientError::Overflow);
+                };
+                ans = match ans.checked_mul(term) {
+                    Some(ans) => ans,
+                    None => return Err(JordanTotientError::Overflow),
+                };
+            }
+
+            if n == 1 {
+                break;
+            }
+            i += 1;
+        }
+
+        if n == 1 {
+            Ok(ans)
+        } else {
+            Err(JordanTotientError::Partial(PartialJordanTotient {
+                jordan_totient_using_known_primes: ans,
+                product_of_unknown_prime_factors: n,
+            }))
+        }
+    }
+
+    /// Returns the number of trailing zeros of `n!` written in the given `base`.
+    ///
+    /// Computed as the minimum, over the distinct prime factors `p` of `base`, of
+    /// [`prime_in_factorial(p, n)`](prime_in_factorial) divided by the multiplicity of `p` in `base`.
+    ///
+    /// Returns [`None`] if `base` is smaller than 2, or has a prime factor that is not present
+    /// in the cache.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// const CACHE: Primes<3> = Primes::new(); // [2, 3, 5]
+    /// // 10! = 3628800, which ends in 2 zeros when written in base 10 = 2 * 5.
+    /// assert_eq!(CACHE.factorial_trailing_zeros(10, 10), Some(2));
+    /// // 10! = 2^8 * 3^4 * 5^2 * 7, so it ends in 8 zeros in base 2.
+    /// assert_eq!(CACHE.factorial_trailing_zeros(10, 2), Some(8));
+    /// assert_eq!(CACHE.factorial_trailing_zeros(10, 14), None); // 7 is not present in the cache
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn factorial_trailing_zeros(
+        &self,
+        n: Underlying,
+        base: Underlying,
+    ) -> Option<Underlying> {
+        if base < 2 {
+            return None;
+        }
+
+        let mut remainder = base;
+        let mut min_zeros: Option<Underlying> = None;
+        let mut i = 0;
+        while let Some(&prime) = self.get(i) {
+            if remainder % prime == 0 {
+                let mut exponent_in_base: Underlying = 0;
+                while remainder % prime == 0 {
+                    remainder /= prime;
+                    exponent_in_base += 1;
+                }
+
+                let zeros = (prime_in_factorial(prime as u64, n as u64) / exponent_in_base as u64)
+                    as Underlying;
+
+                min_zeros = Some(match min_zeros {
+                    Some(current) if current < zeros => current,
+                    _ => zeros,
+                });
+            }
+            if remainder == 1 {
+                break;
+            }
+            i += 1;
+        }
+
+        if remainder == 1 {
+            min_zeros
+        } else {
+            None
+        }
+    }
+
+    /// Returns every prime `<= n` together with its exponent in the prime factorization of `n!`,
+    /// computed with [`prime_in_factorial`] (Legendre's formula).
+    ///
+    /// This is the full prime signature of `n!`, which is otherwise tedious to recover since `n!`
+    /// itself overflows far too quickly to factorize directly; useful for exact rational arithmetic
+    /// involving factorials, such as simplifying binomial coefficients before they're evaluated.
+    ///
+    /// Returns [`None`] if `n` is larger than the largest prime in `self`, even when no prime lies
+    /// between them, or if `F` is smaller than the number of primes `<= n`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// const CACHE: Primes<5> = Primes::new(); // [2, 3, 5, 7, 11]
+    /// // 10! = 2^8 * 3^4 * 5^2 * 7.
+    /// let (primes, exponents) = CACHE.factorial_factorization::<4>(10).unwrap();
+    /// assert_eq!(primes.as_slice(), &[2, 3, 5, 7]);
+    /// assert_eq!(exponents.as_slice(), &[8, 4, 2, 1]);
+    ///
+    /// assert_eq!(CACHE.factorial_factorization::<5>(12), None); // 12 is larger than the largest cached prime
+    /// assert_eq!(CACHE.factorial_factorization::<3>(10), None); // not enough room for 4 primes
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn factorial_factorization<const F: usize>(
+        &self,
+        n: u64,
+    ) -> Option<(ArraySection<Underlying, F>, ArraySection<u64, F>)> {
+        let mut primes = [0 as Underlying; F];
+        let mut exponents = [0u64; F];
+        let mut count = 0;
+
+        let mut i = 0;
+        while let Some(&prime) = self.get(i) {
+            if prime as u64 > n {
+                break;
+            }
+            if count == F {
+                return None;
+            }
+            primes[count] = prime;
+            exponents[count] = prime_in_factorial(prime as u64, n);
+            count += 1;
+            i += 1;
+        }
+
+        if (*self.last() as u64) < n {
+            return None;
+        }
+
+        Some((
+            ArraySection::new(primes, count),
+            ArraySection::new(exponents, count),
+        ))
+    }
+
+    /// Computes the product of the distinct prime factors of `n`, using only the primes in `self`.
+    ///
+    /// Returns [`None`] if `n` is `0`, or has a prime factor that is not present in the cache.
+    const fn distinct_prime_factors_product(&self, n: Underlying) -> Option<Underlying> {
+        if n == 0 {
+            return None;
+        }
+        if n == 1 {
+            return Some(1);
+        }
+
+        let mut remainder = n;
+        let mut product: Underlying = 1;
+        let mut i = 0;
+        while let Some(&prime) = self.get(i) {
+            if remainder % prime == 0 {
+                product *= prime;
+                while remainder % prime == 0 {
+                    remainder /= prime;
+                }
+            }
+            if remainder == 1 {
+                break;
+            }
+            i += 1;
+        }
+
+        if remainder == 1 {
+            Some(product)
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether `a` and `b` have exactly the same set of distinct prime factors, i.e. the
+    /// same prime-factor support.
+    ///
+    /// For example, `12 = 2^2 * 3` and `18 = 2 * 3^2` both have the support `{2, 3}`, so they
+    /// are considered equivalent.
+    ///
+    /// Returns [`None`] if `a` or `b` is `0`, or has a prime factor that is not present in the
+    /// cache.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// const CACHE: Primes<3> = Primes::new(); // [2, 3, 5]
+    /// assert_eq!(CACHE.same_prime_support(12, 18), Some(true)); // both {2, 3}
+    /// assert_eq!(CACHE.same_prime_support(12, 20), Some(false)); // {2, 3} vs {2, 5}
+    /// assert_eq!(CACHE.same_prime_support(12, 7), None); // 7 is not present in the cache
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn same_prime_support(&self, a: Underlying, b: Underlying) -> Option<bool> {
+        let support_a = match self.distinct_prime_factors_product(a) {
+            Some(product) => product,
+            None => return None,
+        };
+        let support_b = match self.distinct_prime_factors_product(b) {
+            Some(product) => product,
+            None => return None,
+        };
+        Some(support_a == support_b)
+    }
+
+    /// Returns the smallest primitive root modulo `p`, i.e. the smallest `g` such that every
+    /// number coprime to `p` is a power of `g` modulo `p`.
+    ///
+    /// Factors `p - 1` using the primes in `self` and then tests candidates `g = 2, 3, ...` by
+    /// checking that `g^((p - 1) / q) != 1 (mod p)` for every distinct prime factor `q` of `p - 1`.
+    ///
+    /// Returns `None` if `p` is not a prime in `self`, or if a prime factor of `p - 1` is not in
+    /// the cache and so can't be found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// const CACHE: Primes<5> = Primes::new(); // [2, 3, 5, 7, 11]
+    /// assert_eq!(CACHE.primitive_root(7), Some(3));
+    /// assert_eq!(CACHE.primitive_root(2), Some(1));
+    /// assert_eq!(CACHE.primitive_root(6), None); // 6 is not prime
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn primitive_root(&self, p: Underlying) -> Option<Underlying> {
+        if self.binary_search(p).is_err() {
+            return None;
+        }
+
+        if p == 2 {
+            return Some(1);
+        }
+
+        let phi = p - 1;
+
+        // A `u32` has at most 9 distinct prime factors, since the product of the first 10 primes
+        // doesn't fit in one.
+        let mut distinct_factors = [0 as Underlying; 9];
+        let mut num_factors = 0;
+
+        let mut remainder = phi;
+        let mut i = 0;
+        while let Some(&prime) = self.get(i) {
+            if remainder % prime == 0 {
+                distinct_factors[num_factors] = prime;
+                num_factors += 1;
+                while remainder % prime == 0 {
+                    remainder /= prime;
+                }
+            }
+            if remainder == 1 {
+                break;
+            }
+            i += 1;
+        }
+
+        if remainder != 1 {
+            return None;
+        }
+
+        let mut candidate: Underlying = 2;
+        while candidate < p {
+            let mut is_primitive_root = true;
+            let mut i = 0;
+            while i < num_factors {
+                let exponent = (phi / distinct_factors[i]) as u128;
+                if mod_pow_u128(candidate as u128, exponent, p as u128) == 1 {
+                    is_primitive_root = false;
+                    break;
+                }
+                i += 1;
+            }
+            if is_primitive_root {
+                return Some(candidate);
+            }
+            candidate += 1;
+        }
+
+        None
+    }
+
+    /// Returns `C(n, k) mod p`, the binomial coefficient reduced modulo the prime `p`, computed
+    /// with [Lucas' theorem](https://en.wikipedia.org/wiki/Lucas%27s_theorem).
+    ///
+    /// Writes `n` and `k` in base `p` and multiplies together the binomial coefficients of their
+    /// digit pairs modulo `p`, each of which is small enough to compute directly. This stays fast
+    /// even when `n` and `k` are huge, since the number of digits is only `log_p(n)`.
+    ///
+    /// Returns [`None`] if `p` is not one of the primes in `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// const CACHE: Primes<5> = Primes::new(); // [2, 3, 5, 7, 11]
+    /// assert_eq!(CACHE.binomial_mod_prime(5, 2, 7), Some(3)); // C(5, 2) = 10 ≡ 3 (mod 7)
+    /// assert_eq!(CACHE.binomial_mod_prime(10, 3, 5), Some(0)); // C(10, 3) = 120 ≡ 0 (mod 5)
+    /// assert_eq!(CACHE.binomial_mod_prime(1_000, 500, 4), None); // 4 is not prime
+    /// ```
+    #[must_use = "the method only returns a new value and does not modify `self`"]
+    pub const fn binomial_mod_prime(&self, mut n: u64, mut k: u64, p: Underlying) -> Option<u64> {
+        if self.binary_search(p).is_err() {
+            return None;
+        }
+
+        if k > n {
+            return Some(0);
+        }
+
+        let p = p as u64;
+        let mut result: u64 = 1;
+        while n > 0 || k > 0 {
+            let n_digit = n % p;
+            let k_digit = k % p;
+            if k_digit > n_digit {
+                return Some(0);
+            }
+            result = (result * small_binomial_mod(n_digit, k_digit, p)) % p;
+            n /= p;
+            k /= p;
+        }
+
+        Some(result)
+    }
+
+    /// Appends `extra` to the primes in `self`, keeping the sorted invariant that the cache relies on,
+    /// and returns the combined primes as an [`ArraySection`] of capacity `M`.
+    ///
+    /// This is useful for stitching together large prime tables that were generated in separate
+    /// segments, without giving up on the guarantee that the result is sorted at the seam.
+    /// Whether the elements of `extra` are prime, or whether a prime is skipped at the seam, is not
+    /// checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `extra` is not strictly increasing, if its first element is not strictly
+    /// greater than [`self.last()`](Self::last), or if `M` is too small to hold every element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::{Primes, cache::ExtendError};
+    /// // Contains [2, 3, 5]
+    /// const CACHE: Primes<3> = Primes::new();
+    ///
+    /// let extended = CACHE.extend_with::<5>(&[7, 11]).unwrap();
+    /// assert_eq!(extended.as_slice(), &[2, 3, 5, 7, 11]);
+    ///
+    /// assert_eq!(CACHE.extend_with::<4>(&[7, 11]), Err(ExtendError::CapacityTooSmall));
+    /// assert_eq!(CACHE.extend_with::<5>(&[4, 11]), Err(ExtendError::NotIncreasing));
+    /// ```
+    pub const fn extend_with<const M: usize>(
+        &self,
+        extra: &[Underlying],
+    ) -> Result<ArraySection<Underlying, M>, ExtendError> {
+        let total = N + extra.len();
+        if total > M {
+            return Err(ExtendError::CapacityTooSmall);
+        }
+
+        let mut out = [0; M];
+        let mut i = 0;
+        while i < N {
+            out[i] = self.0[i];
+            i += 1;
+        }
+
+        let mut previous = self.0[N - 1];
+        let mut j = 0;
+        while j < extra.len() {
+            let value = extra[j];
+            if value <= previous {
+                return Err(ExtendError::NotIncreasing);
+            }
+            out[N + j] = value;
+            previous = value;
+            j += 1;
+        }
+
+        Ok(ArraySection::new(out, total))
+    }
+
+    /// Returns all divisors of `n`, sorted in increasing order, in a fixed-capacity [`ArraySection`].
+    ///
+    /// The divisors are enumerated from the prime factorization of `n` as computed with the primes in `self`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DivisorsError::UnknownPrimeFactor`] if `n` is 0 or has a prime factor larger than every
+    /// prime in `self`, since the full factorization, and thus the full list of divisors, can not be known.
+    ///
+    /// Returns [`DivisorsError::CapacityTooSmall`] if `D` is smaller than the number of divisors of `n`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::{Primes, cache::DivisorsError};
+    /// // Contains [2, 3, 5]
+    /// const CACHE: Primes<3> = Primes::new();
+    ///
+    /// let divisors_of_12 = CACHE.divisors::<6>(12).unwrap();
+    /// assert_eq!(divisors_of_12.as_slice(), &[1, 2, 3, 4, 6, 12]);
+    ///
+    /// assert_eq!(CACHE.divisors::<5>(12), Err(DivisorsError::CapacityTooSmall));
+    /// assert_eq!(CACHE.divisors::<6>(2 * 7), Err(DivisorsError::UnknownPrimeFactor));
+    /// ```
+    pub const fn divisors<const D: usize>(
+        &self,
+        n: Underlying,
+    ) -> Result<ArraySection<Underlying, D>, DivisorsError> {
+        if n == 0 {
+            return Err(DivisorsError::UnknownPrimeFactor);
+        }
+
+        if n == 1 {
+            if D == 0 {
+                return Err(DivisorsError::CapacityTooSmall);
+            }
+            let mut array = [0; D];
+            array[0] = 1;
+            return Ok(ArraySection::new(array, 1));
+        }
+
+        let mut bases = [0; N];
+        let mut exponents = [0usize; N];
+        let mut num_factors = 0;
+
+        let mut remainder = n;
+        let mut i = 0;
+        while let Some(&prime) = self.get(i) {
+            if remainder % prime == 0 {
+                let mut exponent = 0;
+                while remainder % prime == 0 {
+                    remainder /= prime;
+                    exponent += 1;
+                }
+                bases[num_factors] = prime;
+                exponents[num_factors] = exponent;
+                num_factors += 1;
+            }
+            if remainder == 1 {
+                break;
+            }
+            i += 1;
+        }
+
+        if remainder != 1 {
+            return Err(DivisorsError::UnknownPrimeFactor);
+        }
+
+        let mut divisor_count = 1;
+        let mut i = 0;
+        while i < num_factors {
+            divisor_count *= exponents[i] + 1;
+            i += 1;
+        }
+
+        if divisor_count > D {
+            return Err(DivisorsError::CapacityTooSmall);
+        }
+
+        let mut array = [0; D];
+        array[0] = 1;
+        let mut count = 1;
+        let mut i = 0;
+        while i < num_factors {
+            let prime = bases[i];
+            let divisors_so_far = count;
+            let mut power = 1;
+            let mut e = 0;
+            while e < exponents[i] {
+                power *= prime;
+                let mut k = 0;
+                while k < divisors_so_far {
+                    array[count + k] = array[k] * power;
+                    k += 1;
+                }
+                count += divisors_so_far;
+                e += 1;
+            }
+            i += 1;
+        }
+
+        // Insertion sort the populated divisors; `D` is expected to be small enough that this is cheap.
+        let mut i = 1;
+        while i < count {
+            let key = array[i];
+            let mut k = i;
+            while k > 0 && array[k - 1] > key {
+                array[k] = array[k - 1];
+                k -= 1;
+            }
+            array[k] = key;
+            i += 1;
+        }
+
+        Ok(ArraySection::new(array, count))
+    }
+
+    /// Returns an iterator over all divisors of `n` in increasing order, if the full prime
+    /// factorization of `n` is known from `self`.
+    ///
+    /// This is a thin convenience wrapper around [`divisors`](Self::divisors): it factorizes
+    /// `n` the same way and into the same fixed-capacity buffer of size `D`, then hands back the
+    /// buffer's [`ArraySectionIntoIter`], which is already an [`ExactSizeIterator`] (its length
+    /// is τ(n), the divisor count) and a [`FusedIterator`](core::iter::FusedIterator).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DivisorsError::UnknownPrimeFactor`] if `n` is 0 or has a prime factor larger than
+    /// every prime in `self`, and [`DivisorsError::CapacityTooSmall`] if `D` is smaller than the
+    /// number of divisors of `n`. See [`divisors`](Self::divisors) for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::Primes;
+    /// // Contains [2, 3, 5]
+    /// const CACHE: Primes<3> = Primes::new();
+    ///
+    /// let divisors_of_12: Vec<_> = CACHE.divisors_iter::<6>(12).unwrap().collect();
+    /// assert_eq!(divisors_of_12, [1, 2, 3, 4, 6, 12]);
+    /// ```
+    pub fn divisors_iter<const D: usize>(
+        &self,
+        n: Underlying,
+    ) -> Result<ArraySectionIntoIter<Underlying, D>, DivisorsError> {
+        match self.divisors::<D>(n) {
+            Ok(divisors) => Ok(divisors.into_iter()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the sorted multiset of exponents in the prime factorization of `n`,
+    /// discarding which prime each exponent belongs to.
+    ///
+    /// This "shape" of a factorization is useful for classifying numbers by type:
+    /// a squarefree number has a signature of all `1`s, a prime square has the
+    /// signature `[2]`, and so on. For example, `12 = 2^2 * 3` has the signature `[1, 2]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DivisorsError::UnknownPrimeFactor`] if `n` is 0, or has a prime factor larger
+    /// than every prime in `self`, since the full factorization can not be known.
+    ///
+    /// Returns [`DivisorsError::CapacityTooSmall`] if `F` is smaller than the number of distinct
+    /// prime factors of `n`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::{Primes, cache::DivisorsError};
+    /// // Contains [2, 3, 5]
+    /// const CACHE: Primes<3> = Primes::new();
+    ///
+    /// // 12 = 2^2 * 3
+    /// assert_eq!(CACHE.factorization_signature::<2>(12).unwrap().as_slice(), &[1, 2]);
+    /// // 30 = 2 * 3 * 5 is squarefree
+    /// assert_eq!(CACHE.factorization_signature::<3>(30).unwrap().as_slice(), &[1, 1, 1]);
+    ///
+    /// assert_eq!(CACHE.factorization_signature::<1>(12), Err(DivisorsError::CapacityTooSmall));
+    /// assert_eq!(CACHE.factorization_signature::<3>(2 * 7), Err(DivisorsError::UnknownPrimeFactor));
+    /// ```
+    pub const fn factorization_signature<const F: usize>(
+        &self,
+        n: Underlying,
+    ) -> Result<ArraySection<u8, F>, DivisorsError> {
+        if n == 0 {
+            return Err(DivisorsError::UnknownPrimeFactor);
+        }
+
+        let mut exponents = [0u8; F];
+        let mut num_factors = 0;
+
+        let mut remainder = n;
+        let mut i = 0;
+        while let Some(&prime) = self.get(i) {
+            if remainder % prime == 0 {
+                if num_factors == F {
+                    return Err(DivisorsError::CapacityTooSmall);
+                }
+                let mut exponent = 0u8;
+                while remainder % prime == 0 {
+                    remainder /= prime;
+                    exponent += 1;
+                }
+                exponents[num_factors] = exponent;
+                num_factors += 1;
+            }
+            if remainder == 1 {
+                break;
+            }
+            i += 1;
+        }
+
+        if remainder != 1 {
+            return Err(DivisorsError::UnknownPrimeFactor);
+        }
+
+        // Insertion sort the populated exponents; `F` is expected to be small enough that this is cheap.
+        let mut i = 1;
+        while i < num_factors {
+            let key = exponents[i];
+            let mut k = i;
+            while k > 0 && exponents[k - 1] > key {
+                exponents[k] = exponents[k - 1];
+                k -= 1;
+            }
+            exponents[k] = key;
+            i += 1;
+        }
+
+        Ok(ArraySection::new(exponents, num_factors))
+    }
+}
+
+/// The error returned by [`Primes::divisors`] and [`Primes::factorization_signature`]
+/// when their preconditions are violated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DivisorsError {
+    /// `n` was 0, or had a prime factor larger than every prime in the cache.
+    UnknownPrimeFactor,
+    /// The output array was too small to hold the result.
+    CapacityTooSmall,
+}
+
+impl fmt::Display for DivisorsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownPrimeFactor => {
+                write!(f, "`n` has a prime factor that is not present in the cache")
+            }
+            Self::CapacityTooSmall => {
+                write!(f, "the output array was too small to hold the result")
+            }
+        }
+    }
+}
+
+impl core::error::Error for DivisorsError {}
+
+/// The error returned by [`Primes::extend_with`] when its preconditions are violated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ExtendError {
+    /// `extra` was not strictly increasing, or its first element was not strictly greater than the last prime in the cache.
+    NotIncreasing,
+    /// `M` was too small to hold the combined primes.
+    CapacityTooSmall,
+}
+
+impl fmt::Display for ExtendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotIncreasing => write!(
+                f,
+                "`extra` must be strictly increasing and continue after the last prime in the cache"
+            ),
+            Self::CapacityTooSmall => write!(f, "`M` was too small to hold every element"),
+        }
+    }
+}
+
+impl core::error::Error for ExtendError {}
+
+/// Contains the result of a partially successful evaluation of the [`totient`](Primes::totient) function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct PartialTotient {
+    /// The result of computing the totient function with only the primes in the related [`Primes`] struct.
+    pub totient_using_known_primes: Underlying,
+    /// The product of all remaining prime factors of the number.
+    pub product_of_unknown_prime_factors: Underlying,
+}
+
+/// Contains the result of a partially successful evaluation of the
+/// [`count_divisors`](Primes::count_divisors) function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct PartialDivisorCount {
+    /// The result of computing the divisor count with only the primes in the related [`Primes`] struct.
+    pub divisor_count_using_known_primes: Underlying,
+    /// The product of all remaining prime factors of the number.
+    pub product_of_unknown_prime_factors: Underlying,
+}
+
+/// Contains the result of a partially successful evaluation of the
+/// [`largest_prime_factor`](Primes::largest_prime_factor) function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct PartialLargestPrimeFactor {
+    /// The largest prime factor found using only the primes in the related [`Primes`] struct.
+    pub largest_prime_factor_using_known_primes: Underlying,
+    /// The product of all remaining prime factors of the number.
+    pub product_of_unknown_prime_factors: Underlying,
+}
+
+/// Contains the result of a partially successful evaluation of the
+/// [`radical`](Primes::radical) function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct PartialRadical {
+    /// The result of computing the radical with only the primes in the related [`Primes`] struct.
+    pub radical_using_known_primes: Underlying,
+    /// The product of all remaining prime factors of the number.
+    pub product_of_unknown_prime_factors: Underlying,
+}
 
-            if count > 0 {
-                ans *= prime.pow(count - 1) * (prime - 1);
-            }
+/// Contains the result of a partially successful evaluation of the
+/// [`sum_divisors`](Primes::sum_divisors) function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct PartialSumOfDivisors {
+    /// The result of computing the sum of divisors with only the primes in the related [`Primes`] struct.
+    pub sum_of_divisors_using_known_primes: u64,
+    /// The product of all remaining prime factors of the number.
+    pub product_of_unknown_prime_factors: Underlying,
+}
 
-            if n == 1 {
-                break;
-            }
-            i += 1;
-        }
+/// Contains the result of a partially successful evaluation of the
+/// [`jordan_totient`](Primes::jordan_totient) function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct PartialJordanTotient {
+    /// The result of computing the Jordan totient function with only the primes in the related [`Primes`] struct.
+    pub jordan_totient_using_known_primes: u64,
+    /// The product of all remaining prime factors of the number.
+    pub product_of_unknown_prime_factors: Underlying,
+}
 
-        if n == 1 {
-            Ok(ans)
-        } else {
-            Err(PartialTotient {
-                totient_using_known_primes: ans,
-                product_of_unknown_prime_factors: n,
-            })
-        }
-    }
+/// The error returned by [`jordan_totient`](Primes::jordan_totient) when it cannot compute an
+/// exact result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub enum JordanTotientError {
+    /// `n` contains a prime factor that is not present in the cache.
+    Partial(PartialJordanTotient),
+    /// The exact result would overflow a [`u64`].
+    Overflow,
 }
 
-/// Contains the result of a partially successful evaluation of the [`totient`](Primes::totient) function.
+/// Contains the result of a partially successful evaluation of the
+/// [`farey_length`](Primes::farey_length) function.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
 )]
-pub struct PartialTotient {
-    /// The result of computing the totient function with only the primes in the related [`Primes`] struct.
-    pub totient_using_known_primes: Underlying,
-    /// The product of all remaining prime factors of the number.
+pub struct PartialFareyLength {
+    /// The result of computing the Farey sequence length with only the primes in the related [`Primes`] struct.
+    pub farey_length_using_known_primes: u64,
+    /// The product of all remaining prime factors of the first `k` that could not be fully factored.
     pub product_of_unknown_prime_factors: Underlying,
 }
 
@@ -601,6 +2759,13 @@ impl<const N: usize> From<Primes<N>> for [Underlying; N] {
     }
 }
 
+impl<const N: usize> From<Primes<N>> for [u64; N] {
+    #[inline]
+    fn from(const_primes: Primes<N>) -> Self {
+        const_primes.to_u64_array()
+    }
+}
+
 // region: AsRef
 
 impl<const N: usize> AsRef<[Underlying]> for Primes<N> {
@@ -690,6 +2855,15 @@ mod test {
         assert_eq!(BIG, Err(100));
     }
 
+    #[test]
+    fn check_to_u64_array_and_from() {
+        const PRIMES: Primes<5> = Primes::new();
+        const AS_U64: [u64; 5] = PRIMES.to_u64_array();
+        assert_eq!(AS_U64, [2, 3, 5, 7, 11]);
+        assert_eq!(<[u64; 5]>::from(PRIMES), AS_U64);
+        assert_eq!(<[Underlying; 5]>::from(PRIMES), PRIMES.into_array());
+    }
+
     #[test]
     fn test_into_iter() {
         const PRIMES: Primes<10> = Primes::new();
@@ -764,6 +2938,42 @@ mod test {
         assert_eq!(factors_of_270.next(), Some(5));
     }
 
+    #[test]
+    fn check_kth_prime_factor() {
+        const CACHE: Primes<5> = Primes::new();
+        assert_eq!(CACHE.kth_prime_factor(60, 0), Some(2));
+        assert_eq!(CACHE.kth_prime_factor(60, 1), Some(3));
+        assert_eq!(CACHE.kth_prime_factor(60, 2), Some(5));
+        assert_eq!(CACHE.kth_prime_factor(60, 3), None);
+        assert_eq!(CACHE.kth_prime_factor(0, 0), None);
+        assert_eq!(CACHE.kth_prime_factor(1, 0), None);
+
+        const SMALL_CACHE: Primes<2> = Primes::new();
+        assert_eq!(SMALL_CACHE.kth_prime_factor(2 * 3 * 7, 1), Some(3));
+        assert_eq!(SMALL_CACHE.kth_prime_factor(2 * 3 * 7, 2), None);
+    }
+
+    #[test]
+    fn check_smallest_and_largest_prime_factor() {
+        const CACHE: Primes<11> = Primes::new(); // [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31]
+        assert_eq!(CACHE.smallest_prime_factor(15), Some(3));
+        assert_eq!(CACHE.smallest_prime_factor(13), Some(13));
+        assert_eq!(CACHE.smallest_prime_factor(1), None);
+        assert_eq!(CACHE.smallest_prime_factor(0), None);
+
+        assert_eq!(CACHE.largest_prime_factor(0), Ok(0));
+        assert_eq!(CACHE.largest_prime_factor(1), Ok(0));
+        assert_eq!(CACHE.largest_prime_factor(15), Ok(5));
+        assert_eq!(CACHE.largest_prime_factor(13195), Ok(29)); // 13195 = 5 * 7 * 13 * 29
+        assert_eq!(
+            CACHE.largest_prime_factor(2 * 37),
+            Err(PartialLargestPrimeFactor {
+                largest_prime_factor_using_known_primes: 2,
+                product_of_unknown_prime_factors: 37,
+            })
+        );
+    }
+
     #[test]
     fn check_next_prime() {
         const CACHE: Primes<100> = Primes::new();
@@ -825,6 +3035,51 @@ mod test {
         }
     }
 
+    #[test]
+    fn check_gaps() {
+        const PRIMES: Primes<6> = Primes::new(); // [2, 3, 5, 7, 11, 13]
+        let mut gaps = PRIMES.gaps();
+        assert_eq!(gaps.len(), 5);
+        assert_eq!(gaps.next(), Some(1));
+        assert_eq!(gaps.next(), Some(2));
+        assert_eq!(gaps.next(), Some(2));
+        assert_eq!(gaps.next(), Some(4));
+        assert_eq!(gaps.next(), Some(2));
+        assert_eq!(gaps.next(), None);
+    }
+
+    #[test]
+    fn check_sum_and_sum_wrapping() {
+        const SUM: u64 = Primes::<6>::new().sum();
+        const SUM_WRAPPING: Underlying = Primes::<6>::new().sum_wrapping();
+        assert_eq!(SUM, 2 + 3 + 5 + 7 + 11 + 13);
+        assert_eq!(SUM_WRAPPING, 2 + 3 + 5 + 7 + 11 + 13);
+
+        const BIG_SUM: u64 = Primes::<30_000>::new().sum();
+        assert!(BIG_SUM > Underlying::MAX as u64);
+    }
+
+    #[test]
+    fn check_contains() {
+        const PRIMES: Primes<100> = Primes::new();
+        assert!(PRIMES.contains(13));
+        assert!(!PRIMES.contains(42));
+        assert!(!PRIMES.contains(1000));
+        assert_eq!(PRIMES.is_prime(1000), None);
+    }
+
+    #[test]
+    fn check_nth_prime() {
+        const N: usize = 10;
+        const P: Primes<N> = Primes::new();
+        const A: [Underlying; N] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29];
+        assert_eq!(P.nth_prime(0), None);
+        for (i, prime) in A.iter().enumerate() {
+            assert_eq!(P.nth_prime(i + 1), Some(*prime));
+        }
+        assert_eq!(P.nth_prime(N + 1), None);
+    }
+
     #[test]
     fn check_last_and_len() {
         const PRIMES: [Underlying; 10] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29];
@@ -843,6 +3098,59 @@ mod test {
         check_last_n!(1, 2, 3, 4, 5, 6, 7, 8, 9);
     }
 
+    #[test]
+    fn check_max_trial_divisible() {
+        const PRIMES: Primes<5> = Primes::new(); // largest prime is 11
+        assert_eq!(PRIMES.max_trial_divisible(), 121);
+
+        const BIGGER: Primes<100> = Primes::new(); // largest prime is 541
+        assert_eq!(BIGGER.max_trial_divisible(), 541 * 541);
+    }
+
+    #[test]
+    fn check_is_sophie_germain() {
+        const CACHE: Primes<100> = Primes::new();
+        assert_eq!(CACHE.is_sophie_germain(2), Some(true)); // 2*2 + 1 = 5
+        assert_eq!(CACHE.is_sophie_germain(3), Some(true)); // 2*3 + 1 = 7
+        assert_eq!(CACHE.is_sophie_germain(7), Some(false)); // 2*7 + 1 = 15
+        assert_eq!(CACHE.is_sophie_germain(6), Some(false)); // not prime
+        assert_eq!(CACHE.is_sophie_germain(1_000), None); // larger than the cache
+    }
+
+    #[test]
+    fn check_is_safe_prime() {
+        const CACHE: Primes<100> = Primes::new();
+        assert_eq!(CACHE.is_safe_prime(5), Some(true)); // (5 - 1) / 2 = 2
+        assert_eq!(CACHE.is_safe_prime(7), Some(true)); // (7 - 1) / 2 = 3
+        assert_eq!(CACHE.is_safe_prime(13), Some(false)); // (13 - 1) / 2 = 6
+        assert_eq!(CACHE.is_safe_prime(6), Some(false)); // not prime
+        assert_eq!(CACHE.is_safe_prime(1_000), None); // larger than the cache
+    }
+
+    #[test]
+    fn check_as_prime_power() {
+        const CACHE: Primes<3> = Primes::new(); // [2, 3, 5]
+        assert_eq!(CACHE.as_prime_power(8), Some((2, 3)));
+        assert_eq!(CACHE.as_prime_power(9), Some((3, 2)));
+        assert_eq!(CACHE.as_prime_power(5), Some((5, 1)));
+        assert_eq!(CACHE.as_prime_power(0), None);
+        assert_eq!(CACHE.as_prime_power(1), None);
+        assert_eq!(CACHE.as_prime_power(12), None);
+        assert_eq!(CACHE.as_prime_power(49), None);
+    }
+
+    #[test]
+    fn check_factor_multiplicity() {
+        const CACHE: Primes<3> = Primes::new(); // [2, 3, 5]
+        assert_eq!(CACHE.factor_multiplicity(40, 2), Some(3)); // 40 = 2^3 * 5
+        assert_eq!(CACHE.factor_multiplicity(40, 5), Some(1));
+        assert_eq!(CACHE.factor_multiplicity(40, 3), Some(0));
+        assert_eq!(CACHE.factor_multiplicity(0, 2), Some(0));
+        assert_eq!(CACHE.factor_multiplicity(1, 2), Some(0));
+        assert_eq!(CACHE.factor_multiplicity(40, 7), None); // 7 is not present in the cache
+        assert_eq!(CACHE.factor_multiplicity(40, 4), None); // 4 is not prime
+    }
+
     #[test]
     fn check_count_primes_leq() {
         const N: usize = 79;
@@ -863,6 +3171,32 @@ mod test {
         }
     }
 
+    #[test]
+    fn check_prime_pi_at_last_index_does_not_overflow() {
+        // A target equal to the last prime in the cache resolves to index `N - 1`,
+        // the largest index `binary_search` can ever return, so `prime_pi` must
+        // add 1 to it without overflowing.
+        const N: usize = 100;
+        const CACHE: Primes<N> = Primes::new();
+        assert_eq!(CACHE.binary_search(*CACHE.last()), Ok(N - 1));
+        assert_eq!(CACHE.prime_pi(*CACHE.last()), Some(N));
+    }
+
+    #[test]
+    fn check_binary_search_and_prime_pi_near_u32_max() {
+        // The two largest primes smaller than `u32::MAX` (`u32::MAX` itself is composite).
+        let cache = Primes::<2>([4_294_967_279, 4_294_967_291]);
+
+        // None of this should overflow, even though the target is `u32::MAX`.
+        assert_eq!(cache.binary_search(u32::MAX), Err(2));
+        assert_eq!(cache.is_prime(u32::MAX), None);
+        assert_eq!(cache.prime_pi(u32::MAX), None);
+
+        // The last prime in the cache itself must still resolve correctly.
+        assert_eq!(cache.prime_pi(4_294_967_291), Some(2));
+        assert_eq!(cache.is_prime(4_294_967_291), Some(true));
+    }
+
     #[test]
     fn check_iter() {
         const P: Primes<10> = Primes::new();
@@ -871,6 +3205,100 @@ mod test {
         }
     }
 
+    #[test]
+    fn check_extend_with() {
+        const CACHE: Primes<3> = Primes::new();
+        let extended = CACHE.extend_with::<5>(&[7, 11]).unwrap();
+        assert_eq!(extended.as_slice(), &[2, 3, 5, 7, 11]);
+        assert_eq!(
+            CACHE.extend_with::<4>(&[7, 11]),
+            Err(ExtendError::CapacityTooSmall)
+        );
+        assert_eq!(
+            CACHE.extend_with::<5>(&[4, 11]),
+            Err(ExtendError::NotIncreasing)
+        );
+        assert_eq!(
+            CACHE.extend_with::<5>(&[7, 7]),
+            Err(ExtendError::NotIncreasing)
+        );
+        assert_eq!(CACHE.extend_with::<3>(&[]).unwrap().as_slice(), &[2, 3, 5]);
+    }
+
+    #[test]
+    fn check_divisors() {
+        const CACHE: Primes<3> = Primes::new();
+        assert_eq!(
+            CACHE.divisors::<6>(12).unwrap().as_slice(),
+            &[1, 2, 3, 4, 6, 12]
+        );
+        assert_eq!(CACHE.divisors::<2>(5).unwrap().as_slice(), &[1, 5]);
+        assert_eq!(CACHE.divisors::<1>(1).unwrap().as_slice(), &[1]);
+        assert_eq!(
+            CACHE.divisors::<5>(12),
+            Err(DivisorsError::CapacityTooSmall)
+        );
+        assert_eq!(
+            CACHE.divisors::<6>(2 * 7),
+            Err(DivisorsError::UnknownPrimeFactor)
+        );
+        assert_eq!(
+            CACHE.divisors::<1>(0),
+            Err(DivisorsError::UnknownPrimeFactor)
+        );
+        assert_eq!(CACHE.divisors::<0>(1), Err(DivisorsError::CapacityTooSmall));
+    }
+
+    #[test]
+    fn check_divisors_iter() {
+        const CACHE: Primes<3> = Primes::new();
+
+        let mut divisors_of_12 = CACHE.divisors_iter::<6>(12).unwrap();
+        assert_eq!(divisors_of_12.len(), 6);
+        assert!(divisors_of_12.clone().eq([1, 2, 3, 4, 6, 12]));
+        assert_eq!(divisors_of_12.next(), Some(1));
+        assert_eq!(divisors_of_12.len(), 5);
+        assert!(divisors_of_12.eq([2, 3, 4, 6, 12]));
+
+        assert_eq!(
+            CACHE.divisors_iter::<5>(12).err(),
+            Some(DivisorsError::CapacityTooSmall)
+        );
+        assert_eq!(
+            CACHE.divisors_iter::<6>(2 * 7).err(),
+            Some(DivisorsError::UnknownPrimeFactor)
+        );
+    }
+
+    #[test]
+    fn check_factorization_signature() {
+        const CACHE: Primes<3> = Primes::new();
+        assert_eq!(
+            CACHE.factorization_signature::<2>(12).unwrap().as_slice(),
+            &[1, 2]
+        );
+        assert_eq!(
+            CACHE.factorization_signature::<3>(30).unwrap().as_slice(),
+            &[1, 1, 1]
+        );
+        assert_eq!(
+            CACHE.factorization_signature::<1>(8).unwrap().as_slice(),
+            &[3]
+        );
+        assert_eq!(
+            CACHE.factorization_signature::<1>(12),
+            Err(DivisorsError::CapacityTooSmall)
+        );
+        assert_eq!(
+            CACHE.factorization_signature::<3>(2 * 7),
+            Err(DivisorsError::UnknownPrimeFactor)
+        );
+        assert_eq!(
+            CACHE.factorization_signature::<3>(0),
+            Err(DivisorsError::UnknownPrimeFactor)
+        );
+    }
+
     #[test]
     fn check_totient() {
         const TOTIENTS: [Underlying; 101] = [
@@ -911,6 +3339,318 @@ mod test {
         }
     }
 
+    #[test]
+    fn check_count_divisors() {
+        const CACHE: Primes<3> = Primes::new(); // [2, 3, 5]
+        assert_eq!(CACHE.count_divisors(0), Ok(0));
+        assert_eq!(CACHE.count_divisors(1), Ok(1));
+        assert_eq!(CACHE.count_divisors(6), Ok(4)); // 1, 2, 3, 6
+        assert_eq!(CACHE.count_divisors(12), Ok(6)); // 1, 2, 3, 4, 6, 12
+        assert_eq!(
+            CACHE.count_divisors(2 * 7),
+            Err(PartialDivisorCount {
+                divisor_count_using_known_primes: 2,
+                product_of_unknown_prime_factors: 7,
+            })
+        );
+    }
+
+    #[test]
+    fn check_sum_divisors() {
+        const CACHE: Primes<11> = Primes::new(); // [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31]
+        assert_eq!(CACHE.sum_divisors(0), Ok(0));
+        assert_eq!(CACHE.sum_divisors(1), Ok(1));
+        assert_eq!(CACHE.sum_divisors(6), Ok(12)); // 1 + 2 + 3 + 6
+        assert_eq!(CACHE.sum_divisors(28), Ok(56)); // 1 + 2 + 4 + 7 + 14 + 28
+        assert_eq!(
+            CACHE.sum_divisors(2 * 37),
+            Err(PartialSumOfDivisors {
+                sum_of_divisors_using_known_primes: 3, // 1 + 2
+                product_of_unknown_prime_factors: 37,  // 37 is not present in the cache
+            })
+        );
+    }
+
+    #[test]
+    fn check_is_squarefree_and_radical() {
+        const CACHE: Primes<3> = Primes::new(); // [2, 3, 5]
+        assert_eq!(CACHE.is_squarefree(0), None);
+        assert_eq!(CACHE.is_squarefree(1), Some(true));
+        assert_eq!(CACHE.is_squarefree(2 * 3 * 5), Some(true));
+        assert_eq!(CACHE.is_squarefree(12), Some(false)); // 12 = 2^2 * 3
+        assert_eq!(CACHE.is_squarefree(2 * 7), None); // 7 is not present in the cache
+
+        assert_eq!(CACHE.radical(0), Ok(0));
+        assert_eq!(CACHE.radical(1), Ok(1));
+        assert_eq!(CACHE.radical(12), Ok(6)); // 12 = 2^2 * 3
+        assert_eq!(CACHE.radical(2 * 2 * 3 * 3 * 5), Ok(30));
+        assert_eq!(
+            CACHE.radical(2 * 7),
+            Err(PartialRadical {
+                radical_using_known_primes: 2,
+                product_of_unknown_prime_factors: 7,
+            })
+        );
+    }
+
+    #[test]
+    fn check_jordan_totient() {
+        const CACHE: Primes<3> = Primes::new(); // [2, 3, 5]
+
+        // J_1 is the ordinary totient. Restrict to numbers whose prime factors are all in the
+        // cache, i.e. 2,3,5-smooth ones, so `totient` doesn't error.
+        for n in [
+            1, 2, 3, 4, 5, 6, 8, 9, 10, 12, 15, 16, 18, 20, 24, 25, 27, 30u32,
+        ] {
+            assert_eq!(
+                CACHE.jordan_totient(n, 1),
+                Ok(u64::from(CACHE.totient(n).unwrap()))
+            );
+        }
+
+        // J_2(6) = 6^2 * (1 - 1/4) * (1 - 1/9) = 24.
+        assert_eq!(CACHE.jordan_totient(2 * 3, 2), Ok(24));
+        // J_2(p) = p^2 - 1.
+        assert_eq!(CACHE.jordan_totient(5, 2), Ok(24));
+        assert_eq!(CACHE.jordan_totient(0, 3), Ok(0));
+        assert_eq!(CACHE.jordan_totient(1, 3), Ok(1));
+
+        assert_eq!(
+            CACHE.jordan_totient(2 * 7, 1),
+            Err(JordanTotientError::Partial(PartialJordanTotient {
+                jordan_totient_using_known_primes: 1,
+                product_of_unknown_prime_factors: 7,
+            }))
+        );
+
+        // Realistic in-range inputs whose exact `J_k` would overflow a `u64` must error instead
+        // of panicking.
+        assert_eq!(
+            CACHE.jordan_totient(2 * 3, 100),
+            Err(JordanTotientError::Overflow)
+        );
+    }
+
+    #[test]
+    fn check_farey_length() {
+        const CACHE: Primes<3> = Primes::new(); // [2, 3, 5]
+
+        // Known small Farey sequence lengths (OEIS A005728).
+        assert_eq!(CACHE.farey_length(0), Ok(1));
+        assert_eq!(CACHE.farey_length(1), Ok(2));
+        assert_eq!(CACHE.farey_length(2), Ok(3));
+        assert_eq!(CACHE.farey_length(3), Ok(5));
+        assert_eq!(CACHE.farey_length(4), Ok(7));
+        assert_eq!(CACHE.farey_length(5), Ok(11));
+        assert_eq!(CACHE.farey_length(6), Ok(13));
+
+        assert_eq!(
+            CACHE.farey_length(7),
+            Err(PartialFareyLength {
+                farey_length_using_known_primes: 14,
+                product_of_unknown_prime_factors: 7,
+            })
+        );
+    }
+
+    #[test]
+    fn check_mobius_and_mertens() {
+        const CACHE: Primes<100> = Primes::new(); // covers primes up to 541
+        const MOBIUS: [i8; 11] = [0, 1, -1, -1, 0, -1, 1, -1, 0, 0, 1];
+        for (n, &mu) in MOBIUS.iter().enumerate().skip(1) {
+            assert_eq!(CACHE.mobius(n as Underlying), Some(mu));
+        }
+        assert_eq!(CACHE.mobius(0), None);
+        assert_eq!(CACHE.mobius(1), Some(1));
+        assert_eq!(CACHE.mobius(6), Some(1));
+        assert_eq!(CACHE.mobius(12), Some(0)); // 12 = 2^2 * 3, has a squared factor
+        assert_eq!(CACHE.mobius(30), Some(-1)); // 30 = 2 * 3 * 5, squarefree with 3 factors
+
+        const MERTENS: [i32; 11] = [0, 1, 0, -1, -1, -2, -1, -2, -2, -2, -1];
+        for (x, &m) in MERTENS.iter().enumerate().skip(1) {
+            assert_eq!(CACHE.mertens(x as Underlying), Some(m));
+        }
+
+        const SMALL_CACHE: Primes<3> = Primes::new(); // [2, 3, 5]
+        assert_eq!(SMALL_CACHE.mobius(2 * 7), None); // 7 is not present in the cache
+        assert_eq!(SMALL_CACHE.mertens(6), Some(-1));
+        assert_eq!(SMALL_CACHE.mertens(7), None); // mobius(7) needs a prime outside the cache
+    }
+
+    #[test]
+    fn check_sum_of_divisors_and_is_perfect() {
+        const CACHE: Primes<11> = Primes::new(); // [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31]
+        assert_eq!(CACHE.sum_of_divisors(1), Some(1));
+        assert_eq!(CACHE.sum_of_divisors(6), Some(12));
+        assert_eq!(CACHE.sum_of_divisors(12), Some(28));
+        assert_eq!(CACHE.sum_of_divisors(28), Some(56));
+        assert_eq!(CACHE.sum_of_divisors(496), Some(992));
+        assert_eq!(CACHE.sum_of_divisors(0), None);
+        assert_eq!(CACHE.sum_of_divisors(2 * 37), None); // 37 is not present in the cache
+
+        assert_eq!(CACHE.is_perfect(6), Some(true));
+        assert_eq!(CACHE.is_perfect(28), Some(true));
+        assert_eq!(CACHE.is_perfect(496), Some(true));
+        assert_eq!(CACHE.is_perfect(12), Some(false));
+        assert_eq!(CACHE.is_perfect(1), Some(false));
+        assert_eq!(CACHE.is_perfect(0), None);
+        assert_eq!(CACHE.is_perfect(2 * 37), None); // 37 is not present in the cache
+    }
+
+    #[test]
+    fn check_aliquot_sum_and_abundance_class() {
+        const CACHE: Primes<3> = Primes::new(); // [2, 3, 5]
+        assert_eq!(CACHE.aliquot_sum(6), Some(6));
+        assert_eq!(CACHE.aliquot_sum(12), Some(16));
+        assert_eq!(CACHE.aliquot_sum(8), Some(7));
+        assert_eq!(CACHE.aliquot_sum(0), None);
+        assert_eq!(CACHE.aliquot_sum(2 * 7), None); // 7 is not present in the cache
+
+        assert_eq!(CACHE.abundance_class(8), Some(Ordering::Less));
+        assert_eq!(CACHE.abundance_class(6), Some(Ordering::Equal));
+        assert_eq!(CACHE.abundance_class(12), Some(Ordering::Greater));
+        assert_eq!(CACHE.abundance_class(0), None);
+        assert_eq!(CACHE.abundance_class(2 * 7), None); // 7 is not present in the cache
+    }
+
+    #[test]
+    fn check_factorial_trailing_zeros() {
+        const CACHE: Primes<3> = Primes::new(); // [2, 3, 5]
+        assert_eq!(CACHE.factorial_trailing_zeros(10, 10), Some(2));
+        assert_eq!(CACHE.factorial_trailing_zeros(10, 2), Some(8));
+        assert_eq!(CACHE.factorial_trailing_zeros(10, 5), Some(2));
+        assert_eq!(CACHE.factorial_trailing_zeros(0, 10), Some(0));
+        assert_eq!(CACHE.factorial_trailing_zeros(10, 1), None);
+        assert_eq!(CACHE.factorial_trailing_zeros(10, 0), None);
+        assert_eq!(CACHE.factorial_trailing_zeros(10, 14), None); // 7 is not present in the cache
+    }
+
+    #[test]
+    fn check_factorial_factorization() {
+        const CACHE: Primes<5> = Primes::new(); // [2, 3, 5, 7, 11]
+
+        // 10! = 2^8 * 3^4 * 5^2 * 7.
+        let (primes, exponents) = CACHE.factorial_factorization::<4>(10).unwrap();
+        assert_eq!(primes.as_slice(), &[2, 3, 5, 7]);
+        assert_eq!(exponents.as_slice(), &[8, 4, 2, 1]);
+
+        // 5! = 2^3 * 3 * 5.
+        let (primes, exponents) = CACHE.factorial_factorization::<4>(5).unwrap();
+        assert_eq!(primes.as_slice(), &[2, 3, 5]);
+        assert_eq!(exponents.as_slice(), &[3, 1, 1]);
+
+        // 12 is larger than 11, the largest cached prime, even though no prime lies between them.
+        assert_eq!(CACHE.factorial_factorization::<5>(12), None);
+        assert_eq!(CACHE.factorial_factorization::<3>(10), None); // not enough room for 4 primes
+        assert_eq!(CACHE.factorial_factorization::<4>(0).unwrap().0.len(), 0); // 0! = 1
+    }
+
+    #[test]
+    fn check_liouville() {
+        const CACHE: Primes<100> = Primes::new(); // covers primes up to 541
+        const LIOUVILLE: [i8; 11] = [1, 1, -1, -1, 1, -1, 1, -1, -1, 1, 1];
+        for (n, &lambda) in LIOUVILLE.iter().enumerate().skip(1) {
+            assert_eq!(CACHE.liouville(n as Underlying), Some(lambda));
+        }
+        assert_eq!(CACHE.liouville(0), None);
+
+        const SMALL_CACHE: Primes<3> = Primes::new(); // [2, 3, 5]
+        assert_eq!(SMALL_CACHE.liouville(2 * 7), None); // 7 is not present in the cache
+        assert_eq!(SMALL_CACHE.liouville(2 * 2 * 3), Some(-1));
+    }
+
+    #[test]
+    fn check_sum_of_prime_factors() {
+        const CACHE: Primes<3> = Primes::new(); // [2, 3, 5]
+        assert_eq!(CACHE.sum_of_prime_factors(1), Some(0));
+        assert_eq!(CACHE.sum_of_prime_factors(18), Some(5)); // 18 = 2 * 3^2
+        assert_eq!(CACHE.sum_of_prime_factors(0), None);
+        assert_eq!(CACHE.sum_of_prime_factors(2 * 7), None); // 7 is not present in the cache
+
+        assert_eq!(CACHE.sum_of_prime_factors_with_multiplicity(1), Some(0));
+        assert_eq!(CACHE.sum_of_prime_factors_with_multiplicity(18), Some(8)); // 2 + 3 + 3
+        assert_eq!(CACHE.sum_of_prime_factors_with_multiplicity(0), None);
+        assert_eq!(
+            CACHE.sum_of_prime_factors_with_multiplicity(2 * 7),
+            None // 7 is not present in the cache
+        );
+
+        // The Ruth-Aaron pair (714, 715) both sum to 29 with multiplicity.
+        const BIGGER_CACHE: Primes<6> = Primes::new(); // [2, 3, 5, 7, 11, 13]
+        assert_eq!(
+            BIGGER_CACHE.sum_of_prime_factors_with_multiplicity(714),
+            None
+        );
+        assert_eq!(
+            BIGGER_CACHE.sum_of_prime_factors_with_multiplicity(715),
+            Some(29)
+        );
+    }
+
+    #[test]
+    fn check_is_ruth_aaron_pair() {
+        const CACHE: Primes<6> = Primes::new(); // [2, 3, 5, 7, 11, 13]
+                                                // Classic small Ruth-Aaron pairs.
+        assert_eq!(CACHE.is_ruth_aaron_pair(5), Some(true)); // sopfr(5) = 5, sopfr(6) = 2 + 3 = 5
+        assert_eq!(CACHE.is_ruth_aaron_pair(8), Some(true)); // sopfr(8) = 2 + 2 + 2 = 6, sopfr(9) = 3 + 3 = 6
+        assert_eq!(CACHE.is_ruth_aaron_pair(77), Some(true)); // sopfr(77) = 7 + 11 = 18, sopfr(78) = 2 + 3 + 13 = 18
+
+        assert_eq!(CACHE.is_ruth_aaron_pair(9), Some(false)); // sopfr(9) = 6, sopfr(10) = 2 + 5 = 7
+
+        assert_eq!(CACHE.is_ruth_aaron_pair(0), None);
+        assert_eq!(CACHE.is_ruth_aaron_pair(2 * 17), None); // 17 is not present in the cache
+    }
+
+    #[test]
+    fn check_same_prime_support() {
+        const CACHE: Primes<3> = Primes::new(); // [2, 3, 5]
+        assert_eq!(CACHE.same_prime_support(12, 18), Some(true)); // {2, 3} and {2, 3}
+        assert_eq!(CACHE.same_prime_support(12, 20), Some(false)); // {2, 3} and {2, 5}
+        assert_eq!(CACHE.same_prime_support(6, 30), Some(false)); // {2, 3} and {2, 3, 5}
+        assert_eq!(CACHE.same_prime_support(6, 36), Some(true)); // {2, 3} and {2, 3}
+        assert_eq!(CACHE.same_prime_support(4, 8), Some(true)); // both just {2}
+        assert_eq!(CACHE.same_prime_support(12, 7), None); // 7 is not present in the cache
+        assert_eq!(CACHE.same_prime_support(7, 12), None);
+        assert_eq!(CACHE.same_prime_support(0, 12), None);
+        assert_eq!(CACHE.same_prime_support(1, 1), Some(true));
+    }
+
+    #[test]
+    fn check_primitive_root() {
+        const CACHE: Primes<5> = Primes::new(); // [2, 3, 5, 7, 11]
+        assert_eq!(CACHE.primitive_root(2), Some(1));
+        assert_eq!(CACHE.primitive_root(3), Some(2));
+        assert_eq!(CACHE.primitive_root(5), Some(2));
+        assert_eq!(CACHE.primitive_root(7), Some(3));
+        assert_eq!(CACHE.primitive_root(11), Some(2));
+        assert_eq!(CACHE.primitive_root(6), None); // not prime
+        assert_eq!(CACHE.primitive_root(4), None); // not prime
+
+        const SMALL_CACHE: Primes<3> = Primes::new(); // [2, 3, 5]
+                                                      // 5 - 1 = 4 = 2^2, fully factorable from the cache.
+        assert_eq!(SMALL_CACHE.primitive_root(5), Some(2));
+        // 13 isn't even in the cache.
+        assert_eq!(SMALL_CACHE.primitive_root(13), None);
+    }
+
+    #[test]
+    fn check_binomial_mod_prime() {
+        const CACHE: Primes<6> = Primes::new(); // [2, 3, 5, 7, 11, 13]
+
+        assert_eq!(CACHE.binomial_mod_prime(5, 2, 7), Some(3)); // C(5, 2) = 10
+        assert_eq!(CACHE.binomial_mod_prime(10, 3, 5), Some(0)); // C(10, 3) = 120
+        assert_eq!(CACHE.binomial_mod_prime(1_000, 500, 7), Some(4));
+        assert_eq!(CACHE.binomial_mod_prime(20, 10, 13), Some(0));
+        assert_eq!(CACHE.binomial_mod_prime(0, 0, 2), Some(1));
+        assert_eq!(CACHE.binomial_mod_prime(7, 0, 7), Some(1));
+        assert_eq!(CACHE.binomial_mod_prime(7, 7, 7), Some(1));
+        assert_eq!(CACHE.binomial_mod_prime(6, 3, 3), Some(2));
+
+        assert_eq!(CACHE.binomial_mod_prime(3, 5, 7), Some(0)); // k > n
+        assert_eq!(CACHE.binomial_mod_prime(1_000, 500, 4), None); // 4 is not prime
+        assert_eq!(CACHE.binomial_mod_prime(1_000, 500, 17), None); // 17 is not in the cache
+    }
+
     #[cfg(feature = "zerocopy")]
     #[test]
     fn test_as_bytes() {
@@ -927,4 +3667,19 @@ mod test {
         assert_eq!(serde_json::to_string(&P).unwrap(), STRING_VERSION);
         assert_eq!(P, serde_json::from_str(STRING_VERSION).unwrap());
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_rejects_tampered_data() {
+        // Not strictly increasing.
+        assert!(serde_json::from_str::<Primes<3>>("[2,5,3]").is_err());
+        // Contains a composite number.
+        assert!(serde_json::from_str::<Primes<3>>("[2,3,4]").is_err());
+        // Doesn't start at 2.
+        assert!(serde_json::from_str::<Primes<3>>("[3,5,7]").is_err());
+        // Skips the prime 5 between 3 and 7.
+        assert!(serde_json::from_str::<Primes<3>>("[2,3,7]").is_err());
+        // Valid input still deserializes.
+        assert!(serde_json::from_str::<Primes<3>>("[2,3,5]").is_ok());
+    }
 }
@@ -0,0 +1,46 @@
+use super::Underlying;
+use core::iter::FusedIterator;
+
+/// A borrowing iterator over the gaps between consecutive primes in a [`Primes`](super::Primes).
+///
+/// Created by the [`gaps`](super::Primes::gaps) method on [`Primes`](super::Primes), see it for
+/// more information.
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct PrimeGaps<'a>(core::slice::Windows<'a, Underlying>);
+
+impl<'a> PrimeGaps<'a> {
+    pub(super) fn new(windows: core::slice::Windows<'a, Underlying>) -> Self {
+        Self(windows)
+    }
+}
+
+impl Iterator for PrimeGaps<'_> {
+    type Item = Underlying;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|pair| pair[1] - pair[0])
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl ExactSizeIterator for PrimeGaps<'_> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl FusedIterator for PrimeGaps<'_> {}
+
+impl DoubleEndedIterator for PrimeGaps<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|pair| pair[1] - pair[0])
+    }
+}
@@ -2,6 +2,7 @@
 
 #[cfg(not(feature = "fast_test"))]
 use crate::integer_math::{mod_mul, mod_pow};
+use crate::integer_math::{mod_mul_u128, mod_pow_u128};
 
 /// Returns whether `n` is prime.
 ///
@@ -26,6 +27,742 @@ pub const fn is_prime(n: u64) -> bool {
         machine_prime::is_prime(n)
     }
 
+    #[cfg(not(feature = "fast_test"))]
+    {
+        // Use a small wheel to check up to log2(n) by default.
+        // This keeps the complexity at O(log(n)).
+        let trial_bound = if n < 2 { 0 } else { n.ilog2() as u64 };
+        is_prime_impl(n, trial_bound)
+    }
+}
+
+/// Does trial division up to `trial_bound` and then a deterministic Miller-Rabin primality test.
+#[cfg(not(feature = "fast_test"))]
+const fn is_prime_impl(n: u64, trial_bound: u64) -> bool {
+    // Since we know the maximum size of the numbers we test against
+    // we can use the fact that there are known perfect bases
+    // in order to make the test both fast and deterministic.
+    // This list of witnesses was taken from
+    // <https://en.wikipedia.org/wiki/Miller%E2%80%93Rabin_primality_test#Testing_against_small_sets_of_bases>.
+    const NUM_BASES: usize = 11;
+    const WITNESSES: [(u64, &[u64]); NUM_BASES] = [
+        (2_046, &[2]),
+        (1_373_652, &[2, 3]),
+        (9_080_190, &[31, 73]),
+        (25_326_000, &[2, 3, 5]),
+        (4_759_123_140, &[2, 7, 61]),
+        (1_112_004_669_632, &[2, 13, 23, 1_662_803]),
+        (2_152_302_898_746, &[2, 3, 5, 7, 11]),
+        (3_474_749_660_382, &[2, 3, 5, 7, 11, 13]),
+        (341_550_071_728_320, &[2, 3, 5, 7, 11, 13, 17]),
+        (3_825_123_056_546_413_050, &[2, 3, 5, 7, 11, 13, 17, 19, 23]),
+        (u64::MAX, &[2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37]),
+    ];
+
+    if n == 2 || n == 3 {
+        return true;
+    } else if n <= 1 || n % 2 == 0 || n % 3 == 0 {
+        return false;
+    }
+
+    // Clamp the bound so trial division never tests `n` against itself or a factor that would
+    // require `candidate_factor + 2` to reach `n`, which would wrongly flag a prime as composite.
+    let trial_bound = if trial_bound > n - 3 {
+        n - 3
+    } else {
+        trial_bound
+    };
+
+    let mut candidate_factor = 5;
+    while candidate_factor <= trial_bound {
+        if n % candidate_factor == 0 || n % (candidate_factor + 2) == 0 {
+            return false;
+        }
+        candidate_factor += 6;
+    }
+
+    // Find r such that n = 2^d * r + 1 for some r >= 1
+    let mut d = n - 1;
+    while d % 2 == 0 {
+        d >>= 1;
+    }
+
+    let mut i = 0;
+    while i < NUM_BASES && WITNESSES[i].0 < n {
+        i += 1;
+    }
+    let witnesses = WITNESSES[i].1;
+
+    let mut i = 0;
+    while i < witnesses.len() && witnesses[i] < n {
+        if !miller_test(d, n, witnesses[i]) {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+/// Returns whether `n` is prime, trial-dividing up to `trial_bound` before falling back to a
+/// deterministic Miller-Rabin test, instead of the `log2(n)` bound that [`is_prime`] uses.
+///
+/// A larger `trial_bound` can reject some composites faster than the modular exponentiation in
+/// the Miller-Rabin test would, at the cost of more division for inputs that make it past trial
+/// division. The best bound for a given workload is best found by benchmarking.
+///
+/// If the `fast_test` feature is enabled, `trial_bound` is ignored and this function forwards to
+/// [`machine_prime::is_prime`] just like [`is_prime`] does.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// # use const_primes::is_prime_with_trial_bound;
+/// const CHECK: bool = is_prime_with_trial_bound(18_446_744_073_709_551_557, 10_000);
+/// assert!(CHECK);
+/// assert!(!is_prime_with_trial_bound(91, 10));
+/// ```
+#[must_use]
+pub const fn is_prime_with_trial_bound(n: u64, trial_bound: u64) -> bool {
+    #[cfg(feature = "fast_test")]
+    {
+        let _ = trial_bound;
+        machine_prime::is_prime(n)
+    }
+
+    #[cfg(not(feature = "fast_test"))]
+    {
+        is_prime_impl(n, trial_bound)
+    }
+}
+
+/// Returns whether `n` is prime.
+///
+/// Primality is only defined for `n >= 2`, so this returns `false` for every `n < 2`,
+/// including negative numbers. This avoids the common footgun of casting a negative
+/// number to [`u64`] before calling [`is_prime`], which turns e.g. `-7` into a huge,
+/// and in this case prime, unsigned value.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// # use const_primes::is_prime_signed;
+/// assert!(is_prime_signed(13));
+/// assert!(!is_prime_signed(-7));
+/// assert!(!is_prime_signed(0));
+/// assert!(!is_prime_signed(1));
+/// ```
+#[must_use]
+pub const fn is_prime_signed(n: i64) -> bool {
+    if n < 2 {
+        false
+    } else {
+        is_prime(n as u64)
+    }
+}
+
+/// Reverses the decimal digits of `n`.
+const fn reverse_decimal(mut n: u64) -> u64 {
+    let mut reversed = 0;
+    while n > 0 {
+        reversed = reversed * 10 + n % 10;
+        n /= 10;
+    }
+    reversed
+}
+
+/// Returns whether `n` is an emirp: a prime whose decimal digits, reversed, form a
+/// *different* number that is also prime.
+///
+/// Palindromic primes are excluded, since their reversal is themselves rather than a different number.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// # use const_primes::is_emirp;
+/// assert!(is_emirp(13)); // 13 and its reversal, 31, are both prime.
+/// assert!(!is_emirp(11)); // 11 is a palindrome, so its reversal isn't a different number.
+/// assert!(!is_emirp(15)); // 15 isn't prime.
+/// ```
+#[must_use]
+pub const fn is_emirp(n: u64) -> bool {
+    if !is_prime(n) {
+        return false;
+    }
+
+    let reversed = reverse_decimal(n);
+    if reversed == n {
+        return false;
+    }
+
+    is_prime(reversed)
+}
+
+/// The size below which plain trial division outperforms the Miller-Rabin test in [`is_prime`]
+/// for `u32` inputs, as measured by the "u32 primality testing" group in `benches/prime_benches.rs`.
+const TRIAL_DIVISION_THRESHOLD: u32 = 1 << 16;
+
+/// Does trial division up to `isqrt(n)`.
+const fn trial_division_is_prime_u32(n: u32) -> bool {
+    if n < 2 {
+        return false;
+    } else if n < 4 {
+        return true;
+    } else if n % 2 == 0 {
+        return false;
+    }
+
+    let bound = crate::isqrt(n as u64) as u32;
+    let mut factor = 3;
+    while factor <= bound {
+        if n % factor == 0 {
+            return false;
+        }
+        factor += 2;
+    }
+
+    true
+}
+
+/// Returns whether `n` is prime, picking whichever of trial division or the Miller-Rabin test
+/// behind [`is_prime`] is faster for numbers of this size.
+///
+/// For small `u32` inputs, `isqrt(n)` is small enough that trial division's lower constant
+/// overhead beats the modular exponentiation Miller-Rabin needs, but Miller-Rabin wins once
+/// `isqrt(n)` grows past it. The crossover point is calibrated against `benches/prime_benches.rs`.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// # use const_primes::is_prime_u32;
+/// assert!(is_prime_u32(6_700_417));
+/// assert!(!is_prime_u32(6_700_418));
+/// ```
+#[must_use]
+pub const fn is_prime_u32(n: u32) -> bool {
+    if n < TRIAL_DIVISION_THRESHOLD {
+        trial_division_is_prime_u32(n)
+    } else {
+        is_prime(n as u64)
+    }
+}
+
+/// Returns whether `n` is prime, for `n` that may be larger than [`u64::MAX`].
+///
+/// For `n <= u64::MAX` this forwards to [`is_prime`], which is fully deterministic. For larger
+/// `n` there is no known small set of Miller-Rabin witnesses proven to be deterministic, so this
+/// instead runs a [Baillie-PSW test](https://en.wikipedia.org/wiki/Baillie%E2%80%93PSW_primality_test):
+/// a strong Fermat test base 2 followed by a strong Lucas test with parameters chosen by
+/// Selfridge's method. This makes [`is_prime_u128`] a strong probable-primality test rather than
+/// a proof for `n > u64::MAX`: no composite number is known to pass BPSW, but it hasn't been
+/// proven that none exists.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// # use const_primes::is_prime_u128;
+/// // 2^127 - 1, a Mersenne prime.
+/// const CHECK: bool = is_prime_u128(170_141_183_460_469_231_731_687_303_715_884_105_727);
+/// assert!(CHECK);
+/// assert!(!is_prime_u128(1));
+/// ```
+#[must_use]
+pub const fn is_prime_u128(n: u128) -> bool {
+    if n <= u64::MAX as u128 {
+        return is_prime(n as u64);
+    }
+
+    if n % 2 == 0 {
+        return false;
+    }
+
+    // Strong Fermat test, base 2.
+    let mut d = n - 1;
+    while d % 2 == 0 {
+        d >>= 1;
+    }
+    if !miller_test_u128(d, n, 2) {
+        return false;
+    }
+
+    // Every perfect square past `u64::MAX` is composite, and Selfridge's method below would
+    // loop forever looking for a `D` that doesn't exist for one.
+    if is_perfect_square_u128(n) {
+        return false;
+    }
+
+    // Selfridge's method: find the first `D` in 5, -7, 9, -11, 13, ... with Jacobi symbol
+    // `(D/n) == -1`, then take `P = 1`, `Q = (1 - D) / 4`.
+    let mut candidate_d: i64 = 5;
+    let q = loop {
+        let symbol = jacobi_symbol_u128(candidate_d, n);
+        if symbol == -1 {
+            break (1 - candidate_d) / 4;
+        }
+        if symbol == 0 {
+            // `gcd(|candidate_d|, n) > 1`, so `candidate_d` is itself a nontrivial factor of `n`.
+            return false;
+        }
+        candidate_d = if candidate_d > 0 {
+            -(candidate_d + 2)
+        } else {
+            -(candidate_d - 2)
+        };
+    };
+
+    strong_lucas_probable_prime_u128(n, q)
+}
+
+/// Returns whether `n` is a strong probable prime to the base `base`: the result of a single
+/// round of the Miller-Rabin test run against that specific witness.
+///
+/// [`is_prime`] and [`prime_certificate`] run this same round internally, but against a fixed
+/// set of witnesses proven to be deterministic for `n <= u64::MAX`. Exposing it with a
+/// caller-chosen `base` lets users compose their own probabilistic tests or demonstrate that a
+/// Carmichael number can pass a strong probable prime test to some bases while failing others.
+///
+/// Returns `false` for every `n < 2` and every even `n > 2`, since the Miller-Rabin test isn't
+/// defined for them. Reduces `base` modulo `n` first, so `base % n == 0` can't panic and instead
+/// just fails the test, the same as it would for any other witness that shares a factor with `n`.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::is_strong_probable_prime;
+/// assert!(is_strong_probable_prime(13, 2));
+/// assert!(!is_strong_probable_prime(15, 2));
+///
+/// // 2047 = 23 * 89 is the smallest strong pseudoprime to base 2, but not to base 3.
+/// assert!(is_strong_probable_prime(2047, 2));
+/// assert!(!is_strong_probable_prime(2047, 3));
+///
+/// assert!(!is_strong_probable_prime(1, 2));
+/// assert!(!is_strong_probable_prime(4, 2));
+/// ```
+#[must_use]
+pub const fn is_strong_probable_prime(n: u64, base: u64) -> bool {
+    if n == 2 || n == 3 {
+        return true;
+    }
+    if n < 2 || n % 2 == 0 {
+        return false;
+    }
+
+    let mut d = n - 1;
+    while d % 2 == 0 {
+        d >>= 1;
+    }
+
+    miller_test_u128(d as u128, n as u128, (base % n) as u128)
+}
+
+/// Performs a Miller-Rabin test with the witness `k`, for `u128` operands.
+const fn miller_test_u128(mut d: u128, n: u128, k: u128) -> bool {
+    let mut x = mod_pow_u128(k, d, n);
+    if x == 1 || x == n - 1 {
+        return true;
+    }
+
+    while d != n - 1 {
+        x = mod_mul_u128(x, x, n);
+        d *= 2;
+
+        if x == 1 {
+            return false;
+        } else if x == n - 1 {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Returns whether `n` is a perfect square, using the same Newton's method iteration as
+/// [`isqrt`](crate::isqrt) but widened to `u128` for moduli past [`u64::MAX`].
+const fn is_perfect_square_u128(n: u128) -> bool {
+    if n < 2 {
+        return true;
+    }
+
+    let mut x0: u128 = 1 << (n.ilog2() / 2 + 1);
+    let mut x1 = (x0 + n / x0) / 2;
+    while x1 < x0 {
+        x0 = x1;
+        x1 = (x0 + n / x0) / 2;
+    }
+    x0 * x0 == n
+}
+
+/// Computes the Jacobi symbol `(d/n)` for odd `n > 0`, mirroring [`kronecker`](crate::kronecker)'s
+/// algorithm for its odd-modulus case but widened to `u128` for the `n` that [`is_prime_u128`]
+/// selects Lucas parameters for.
+const fn jacobi_symbol_u128(d: i64, n: u128) -> i8 {
+    let mut result: i8 = 1;
+    let mut n = n;
+    let mut a = if d >= 0 {
+        d as u128 % n
+    } else {
+        let abs = (-d) as u128 % n;
+        if abs == 0 {
+            0
+        } else {
+            n - abs
+        }
+    };
+
+    while a != 0 {
+        while a % 2 == 0 {
+            a /= 2;
+            let r = n % 8;
+            if r == 3 || r == 5 {
+                result = -result;
+            }
+        }
+
+        let tmp = a;
+        a = n;
+        n = tmp;
+
+        if a % 4 == 3 && n % 4 == 3 {
+            result = -result;
+        }
+        a %= n;
+    }
+
+    if n == 1 {
+        result
+    } else {
+        0
+    }
+}
+
+/// Reduces the possibly negative `x` into `[0, modulus)`.
+const fn reduce_signed_u128(x: i64, modulus: u128) -> u128 {
+    if x >= 0 {
+        (x as u128) % modulus
+    } else {
+        let abs = (-x) as u128 % modulus;
+        if abs == 0 {
+            0
+        } else {
+            modulus - abs
+        }
+    }
+}
+
+/// Calculates (`a` - `b`) mod `modulus`, for `a, b < modulus`.
+const fn sub_mod_u128(a: u128, b: u128, modulus: u128) -> u128 {
+    if a >= b {
+        a - b
+    } else {
+        modulus - (b - a)
+    }
+}
+
+/// Computes the `k`-th terms `(U_k, V_k)` of the Lucas sequence with `P = 1` and parameter `Q = q`,
+/// reduced modulo `modulus`. Mirrors [`lucas_u_v`](crate::lucas_u_v)'s doubling-identity algorithm,
+/// specialized to `P = 1` and widened to `u128` operands for the strong Lucas test that
+/// [`is_prime_u128`] runs as the second half of its BPSW check.
+const fn lucas_uv_u128(q: i64, k: u128, modulus: u128) -> (u128, u128) {
+    let q_mod = reduce_signed_u128(q, modulus);
+
+    // `(a, b)` holds `(U_n, U_{n+1})` for the `n` represented by the bits of `k` consumed so far.
+    let mut a: u128 = 0;
+    let mut b: u128 = 1;
+
+    let mut i = u128::BITS - k.leading_zeros();
+    while i > 0 {
+        i -= 1;
+
+        let u2n = mod_mul_u128(
+            a,
+            sub_mod_u128(mod_mul_u128(2, b, modulus), a, modulus),
+            modulus,
+        );
+        let u2n1 = sub_mod_u128(
+            mod_mul_u128(b, b, modulus),
+            mod_mul_u128(q_mod, mod_mul_u128(a, a, modulus), modulus),
+            modulus,
+        );
+        a = u2n;
+        b = u2n1;
+
+        if (k >> i) & 1 == 1 {
+            let next_b = sub_mod_u128(b, mod_mul_u128(q_mod, a, modulus), modulus);
+            a = b;
+            b = next_b;
+        }
+    }
+
+    let v = sub_mod_u128(mod_mul_u128(2, b, modulus), a, modulus);
+    (a, v)
+}
+
+/// Runs a strong Lucas probable-primality test on odd `n` with Selfridge parameters `P = 1`,
+/// `Q = q`, as the second half of the BPSW test in [`is_prime_u128`].
+const fn strong_lucas_probable_prime_u128(n: u128, q: i64) -> bool {
+    // Find r and odd s such that n + 1 = 2^r * s.
+    let Some(mut s) = n.checked_add(1) else {
+        // n is `u128::MAX`, which factors as `(2^64 - 1) * (2^64 + 1)`.
+        return false;
+    };
+    let mut r = 0u32;
+    while s % 2 == 0 {
+        s /= 2;
+        r += 1;
+    }
+
+    let (u, mut v) = lucas_uv_u128(q, s, n);
+    if u == 0 || v == 0 {
+        return true;
+    }
+
+    // Double `V_{s * 2^t}` and `Q^{s * 2^t}` directly instead of recomputing the whole Lucas
+    // sequence for every `t`, via the doubling identity `V_{2k} = V_k^2 - 2*Q^k`.
+    let mut qk = mod_pow_u128(reduce_signed_u128(q, n), s, n);
+    let mut t = 1;
+    while t < r {
+        v = sub_mod_u128(mod_mul_u128(v, v, n), mod_mul_u128(2, qk, n), n);
+        if v == 0 {
+            return true;
+        }
+        qk = mod_mul_u128(qk, qk, n);
+        t += 1;
+    }
+
+    false
+}
+
+/// Returns whether `n` is prime and equal to `k! - 1` or `k! + 1` for some `k`.
+///
+/// Computes factorials in a `u128` accumulator so that the comparison never overflows,
+/// and stops as soon as the factorial grows past `n + 1`, at which point no larger `k`
+/// could possibly produce `n`.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// # use const_primes::is_factorial_prime;
+/// assert!(is_factorial_prime(23)); // 4! - 1 = 23, which is prime.
+/// assert!(is_factorial_prime(7)); // 3! + 1 = 7, which is prime.
+/// assert!(!is_factorial_prime(29)); // 29 is prime, but isn't adjacent to any factorial.
+/// ```
+#[must_use]
+pub const fn is_factorial_prime(n: u64) -> bool {
+    if !is_prime(n) {
+        return false;
+    }
+
+    let target = n as u128;
+    let mut factorial: u128 = 1;
+    let mut k: u128 = 1;
+    while factorial <= target + 1 {
+        if factorial == target + 1 || factorial + 1 == target {
+            return true;
+        }
+        k += 1;
+        factorial *= k;
+    }
+
+    false
+}
+
+/// Returns whether `n` is prime and equal to `p_k# - 1` or `p_k# + 1` for some `k`,
+/// where `p_k#` is the primorial of the `k`-th prime, i.e. the product of the first `k` primes.
+///
+/// Generates the first `M` primes with [`primes`](crate::primes) and multiplies them into a
+/// `u128` accumulator one at a time, checking after each one whether the running primorial is
+/// adjacent to `n`. Stops early once the primorial grows past `n + 1`. If `M` is too small to
+/// reach a primorial adjacent to `n`, this returns `false` rather than erroring.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// # use const_primes::is_primorial_prime;
+/// assert!(is_primorial_prime::<1>(3)); // 2# + 1 = 3, which is prime.
+/// assert!(is_primorial_prime::<2>(7)); // 3# + 1 = 7, which is prime.
+/// assert!(is_primorial_prime::<3>(29)); // 5# - 1 = 29, which is prime.
+/// assert!(!is_primorial_prime::<3>(23)); // 23 is prime, but isn't adjacent to any primorial.
+/// ```
+#[must_use]
+pub const fn is_primorial_prime<const M: usize>(n: u64) -> bool {
+    if !is_prime(n) {
+        return false;
+    }
+
+    let factors: [u32; M] = crate::primes();
+    let target = n as u128;
+    let mut primorial: u128 = 1;
+    let mut i = 0;
+    while i < M {
+        primorial *= factors[i] as u128;
+        if primorial == target + 1 || primorial + 1 == target {
+            return true;
+        }
+        if primorial > target + 1 {
+            return false;
+        }
+        i += 1;
+    }
+
+    false
+}
+
+/// Returns whether `n` is a [Fermat number](https://en.wikipedia.org/wiki/Fermat_number),
+/// i.e. `n == 2^(2^k) + 1` for some `k >= 0`, and if so whether it's prime.
+///
+/// Returns [`None`] if `n` is not of Fermat form, and `Some(is_prime(n))` if it is. Detects the
+/// form with bit tricks rather than by searching for `k`: `n - 1` must be a power of two, and the
+/// exponent of that power of two must itself be a power of two.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::is_fermat_prime;
+/// // The five known Fermat primes.
+/// assert_eq!(is_fermat_prime(3), Some(true));
+/// assert_eq!(is_fermat_prime(5), Some(true));
+/// assert_eq!(is_fermat_prime(17), Some(true));
+/// assert_eq!(is_fermat_prime(257), Some(true));
+/// assert_eq!(is_fermat_prime(65_537), Some(true));
+///
+/// // F_5 = 2^32 + 1 = 4294967297 = 641 * 6700417 is a Fermat number, but composite.
+/// assert_eq!(is_fermat_prime(4_294_967_297), Some(false));
+///
+/// // 9 = 2^3 + 1 is not of Fermat form, since 3 is not a power of two.
+/// assert_eq!(is_fermat_prime(9), None);
+/// ```
+#[must_use]
+pub const fn is_fermat_prime(n: u64) -> Option<bool> {
+    let Some(m) = n.checked_sub(1) else {
+        return None;
+    };
+
+    if m == 0 || !m.is_power_of_two() {
+        return None;
+    }
+
+    let exponent = m.trailing_zeros() as u64;
+    if exponent == 0 || !exponent.is_power_of_two() {
+        return None;
+    }
+
+    Some(is_prime(n))
+}
+
+/// Returns whether `primes` consists of strictly increasing prime numbers.
+///
+/// Cross-checks every entry with [`is_prime`], independently of however `primes` was generated.
+/// Intended as a belt-and-suspenders const assertion, e.g. `const { assert!(verify_primes(&PRIMES)) }`.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::verify_primes;
+/// const PRIMES: [u32; 5] = const_primes::primes();
+/// const VERIFIED: bool = verify_primes(&PRIMES);
+/// assert!(VERIFIED);
+///
+/// const NOT_PRIMES: [u32; 3] = [2, 3, 6];
+/// assert!(!verify_primes(&NOT_PRIMES));
+/// ```
+#[must_use = "the function only returns a new value and does not modify its input"]
+pub const fn verify_primes<const N: usize>(primes: &[u32; N]) -> bool {
+    let mut i = 0;
+    while i < N {
+        if !is_prime(primes[i] as u64) {
+            return false;
+        }
+        if i > 0 && primes[i - 1] >= primes[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns `Ok(())` if `n` is prime, or `Err` with a witness that proves it is not:
+/// either the smallest factor found by trial division, or the Miller-Rabin base
+/// that `n` failed.
+///
+/// This always runs its own trial division and Miller-Rabin test, independently of
+/// the `fast_test` feature, so that it can explain a negative answer even when
+/// [`is_prime`] itself just forwards to [`machine_prime::is_prime`].
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// # use const_primes::prime_certificate;
+/// const PRIME: Result<(), u64> = prime_certificate(18_446_744_073_709_551_557);
+/// assert_eq!(PRIME, Ok(()));
+///
+/// const COMPOSITE: Result<(), u64> = prime_certificate(341_550_071_728_321);
+/// assert!(COMPOSITE.is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns the witness to `n`'s compositeness if `n` is not prime.
+///
+/// ```
+/// # use const_primes::prime_certificate;
+/// assert_eq!(prime_certificate(0), Err(0));
+/// assert_eq!(prime_certificate(1), Err(1));
+/// assert_eq!(prime_certificate(15), Err(3));
+/// ```
+#[must_use = "the function only returns a new value and does not modify its input"]
+pub const fn prime_certificate(n: u64) -> Result<(), u64> {
+    if n == 2 || n == 3 {
+        return Ok(());
+    } else if n <= 1 {
+        return Err(n);
+    } else if n % 2 == 0 {
+        return Err(2);
+    } else if n % 3 == 0 {
+        return Err(3);
+    }
+
+    // Use a small wheel to check up to log2(n).
+    // This keeps the complexity at O(log(n)).
+    let mut candidate_factor = 5;
+    let trial_limit = n.ilog2() as u64;
+    while candidate_factor <= trial_limit {
+        if n % candidate_factor == 0 {
+            return Err(candidate_factor);
+        } else if n % (candidate_factor + 2) == 0 {
+            return Err(candidate_factor + 2);
+        }
+        candidate_factor += 6;
+    }
+
+    #[cfg(feature = "fast_test")]
+    {
+        // `machine_prime` does not expose the witness it used internally,
+        // so the best we can report is that trial division found nothing
+        // and a later, opaque check ruled `n` out.
+        if machine_prime::is_prime(n) {
+            Ok(())
+        } else {
+            Err(0)
+        }
+    }
+
     #[cfg(not(feature = "fast_test"))]
     {
         // Since we know the maximum size of the numbers we test against
@@ -48,23 +785,6 @@ pub const fn is_prime(n: u64) -> bool {
             (u64::MAX, &[2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37]),
         ];
 
-        if n == 2 || n == 3 {
-            return true;
-        } else if n <= 1 || n % 2 == 0 || n % 3 == 0 {
-            return false;
-        }
-
-        // Use a small wheel to check up to log2(n).
-        // This keeps the complexity at O(log(n)).
-        let mut candidate_factor = 5;
-        let trial_limit = n.ilog2() as u64;
-        while candidate_factor <= trial_limit {
-            if n % candidate_factor == 0 || n % (candidate_factor + 2) == 0 {
-                return false;
-            }
-            candidate_factor += 6;
-        }
-
         // Find r such that n = 2^d * r + 1 for some r >= 1
         let mut d = n - 1;
         while d % 2 == 0 {
@@ -80,12 +800,12 @@ pub const fn is_prime(n: u64) -> bool {
         let mut i = 0;
         while i < witnesses.len() && witnesses[i] < n {
             if !miller_test(d, n, witnesses[i]) {
-                return false;
+                return Err(witnesses[i]);
             }
             i += 1;
         }
 
-        true
+        Ok(())
     }
 }
 
@@ -113,7 +833,20 @@ const fn miller_test(mut d: u64, n: u64, k: u64) -> bool {
 
 #[cfg(test)]
 mod test {
-    use super::is_prime;
+    use super::{
+        is_emirp, is_factorial_prime, is_fermat_prime, is_prime, is_prime_signed, is_prime_u128,
+        is_prime_u32, is_prime_with_trial_bound, is_primorial_prime, is_strong_probable_prime,
+        prime_certificate, verify_primes, TRIAL_DIVISION_THRESHOLD,
+    };
+
+    #[test]
+    fn check_verify_primes() {
+        const PRIMES: [u32; 5] = crate::primes();
+        assert!(verify_primes(&PRIMES));
+        assert!(!verify_primes(&[2, 3, 6]));
+        assert!(!verify_primes(&[3, 2, 5]));
+        assert!(verify_primes::<0>(&[]));
+    }
 
     #[test]
     fn check_is_prime() {
@@ -139,4 +872,163 @@ mod test {
         assert!(is_prime(341_550_071_728_289));
         assert!(is_prime(3_825_123_056_546_412_979));
     }
+
+    #[test]
+    fn check_prime_certificate() {
+        assert_eq!(prime_certificate(0), Err(0));
+        assert_eq!(prime_certificate(1), Err(1));
+        assert_eq!(prime_certificate(2), Ok(()));
+        assert_eq!(prime_certificate(3), Ok(()));
+        assert_eq!(prime_certificate(4), Err(2));
+        assert_eq!(prime_certificate(9), Err(3));
+        assert_eq!(prime_certificate(15), Err(3));
+        assert_eq!(prime_certificate(18_446_744_073_709_551_557), Ok(()));
+
+        for n in 0..100 {
+            assert_eq!(prime_certificate(n).is_ok(), is_prime(n));
+        }
+    }
+
+    #[test]
+    fn check_is_prime_signed() {
+        assert!(!is_prime_signed(-7));
+        assert!(!is_prime_signed(-2));
+        assert!(!is_prime_signed(0));
+        assert!(!is_prime_signed(1));
+        assert!(is_prime_signed(2));
+        assert!(is_prime_signed(13));
+        assert!(!is_prime_signed(15));
+        for n in 0..100 {
+            assert_eq!(is_prime_signed(n), is_prime(n as u64));
+        }
+    }
+
+    #[test]
+    fn check_is_emirp() {
+        assert!(is_emirp(13));
+        assert!(is_emirp(17));
+        assert!(is_emirp(149));
+        assert!(!is_emirp(11)); // palindromic prime
+        assert!(!is_emirp(2)); // palindromic prime
+        assert!(!is_emirp(15)); // not prime
+        assert!(!is_emirp(31 * 2)); // not prime
+    }
+
+    #[test]
+    fn check_is_factorial_prime() {
+        assert!(is_factorial_prime(2)); // 2! - 1 = 1 is not prime, but 1! + 1 = 2 is.
+        assert!(is_factorial_prime(5)); // 3! - 1 = 5
+        assert!(is_factorial_prime(7)); // 3! + 1 = 7
+        assert!(is_factorial_prime(23)); // 4! - 1 = 23
+        assert!(!is_factorial_prime(29)); // prime, but not adjacent to a factorial
+        assert!(!is_factorial_prime(24)); // adjacent to a factorial, but not prime
+    }
+
+    #[test]
+    fn check_is_primorial_prime() {
+        assert!(is_primorial_prime::<1>(3)); // 2# + 1 = 3
+        assert!(is_primorial_prime::<2>(7)); // 3# + 1 = 7
+        assert!(is_primorial_prime::<2>(5)); // 3# - 1 = 5
+        assert!(is_primorial_prime::<3>(29)); // 5# - 1 = 29
+        assert!(!is_primorial_prime::<3>(23)); // prime, but not adjacent to a primorial
+        assert!(!is_primorial_prime::<1>(7)); // `M` too small to reach 3# + 1 = 7
+    }
+
+    #[test]
+    fn check_is_fermat_prime() {
+        assert_eq!(is_fermat_prime(3), Some(true));
+        assert_eq!(is_fermat_prime(5), Some(true));
+        assert_eq!(is_fermat_prime(17), Some(true));
+        assert_eq!(is_fermat_prime(257), Some(true));
+        assert_eq!(is_fermat_prime(65_537), Some(true));
+
+        assert_eq!(is_fermat_prime(4_294_967_297), Some(false)); // F_5 = 641 * 6700417
+
+        assert_eq!(is_fermat_prime(0), None);
+        assert_eq!(is_fermat_prime(1), None);
+        assert_eq!(is_fermat_prime(2), None); // 2 - 1 = 1 = 2^0, but 0 isn't a power of two
+        assert_eq!(is_fermat_prime(9), None); // 9 - 1 = 8 = 2^3, but 3 isn't a power of two
+        assert_eq!(is_fermat_prime(6), None); // 6 - 1 = 5 is not a power of two
+    }
+
+    #[test]
+    fn check_is_prime_u32() {
+        for n in 0..2_000u32 {
+            assert_eq!(is_prime_u32(n), is_prime(n as u64));
+        }
+
+        // The threshold itself, and its neighbours, should agree with `is_prime`
+        // regardless of which branch of `is_prime_u32` handles them.
+        for n in TRIAL_DIVISION_THRESHOLD - 2..TRIAL_DIVISION_THRESHOLD + 2 {
+            assert_eq!(is_prime_u32(n), is_prime(n as u64));
+        }
+
+        assert!(is_prime_u32(6_700_417));
+        assert!(!is_prime_u32(6_700_418));
+    }
+
+    #[test]
+    fn check_is_prime_with_trial_bound() {
+        for n in 0..1_000 {
+            assert_eq!(is_prime_with_trial_bound(n, 0), is_prime(n));
+            assert_eq!(is_prime_with_trial_bound(n, 5), is_prime(n));
+            assert_eq!(is_prime_with_trial_bound(n, 1_000), is_prime(n));
+        }
+        assert!(is_prime_with_trial_bound(
+            18_446_744_073_709_551_557,
+            10_000
+        ));
+        assert!(!is_prime_with_trial_bound(91, 10));
+    }
+
+    #[test]
+    fn check_is_prime_u128() {
+        // Delegates to `is_prime` for `n <= u64::MAX`.
+        for n in 0..2_000u128 {
+            assert_eq!(is_prime_u128(n), is_prime(n as u64));
+        }
+        assert!(!is_prime_u128(u64::MAX as u128));
+
+        // 2^127 - 1, a Mersenne prime, well past `u64::MAX`.
+        assert!(is_prime_u128(
+            170_141_183_460_469_231_731_687_303_715_884_105_727
+        ));
+        // A prime just above `u64::MAX`.
+        assert!(is_prime_u128(18_446_744_073_709_551_629));
+        // A product of two large primes, still well past `u64::MAX`.
+        assert!(!is_prime_u128(
+            1_329_227_995_784_916_015_866_073_631_529_372_603
+        ));
+        // An even number past `u64::MAX`.
+        assert!(!is_prime_u128(1 << 100));
+        assert!(!is_prime_u128(0));
+        assert!(!is_prime_u128(1));
+
+        // An odd perfect square past `u64::MAX`: (2^32 + 15)^2. It fails the base-2 strong
+        // Fermat test, and the perfect-square check ahead of Selfridge's method would reject it
+        // before the strong Lucas test in any case.
+        assert!(!is_prime_u128(18_446_744_202_558_570_721));
+
+        // `u128::MAX` itself: odd, and factors as `(2^64 - 1) * (2^64 + 1)`. It fails the
+        // base-2 strong Fermat test, since `2^((n - 1) / 2)` is `2^127` modulo `n`.
+        assert!(!is_prime_u128(u128::MAX));
+    }
+
+    #[test]
+    fn check_is_strong_probable_prime() {
+        for n in 0..2_000u64 {
+            assert_eq!(is_strong_probable_prime(n, 2), is_prime(n));
+        }
+
+        // 2047 = 23 * 89 is the smallest strong pseudoprime to base 2, but not to base 3.
+        assert!(is_strong_probable_prime(2047, 2));
+        assert!(!is_strong_probable_prime(2047, 3));
+
+        // Doesn't panic for any of the edge cases it's supposed to handle gracefully.
+        assert!(!is_strong_probable_prime(0, 2));
+        assert!(!is_strong_probable_prime(1, 2));
+        assert!(!is_strong_probable_prime(4, 2));
+        assert!(!is_strong_probable_prime(9, 3)); // base % n == 0
+        assert!(!is_strong_probable_prime(9, 0));
+    }
 }
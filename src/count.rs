@@ -1,4 +1,7 @@
-use crate::sieve;
+use crate::{
+    is_prime, isqrt, sieve,
+    sieve::{sieve_segment, SieveError},
+};
 
 /// Returns an array of size `N` where the value at a given index is how many primes are less than or equal to the index.
 ///
@@ -35,3 +38,310 @@ pub const fn prime_pi<const N: usize>() -> [usize; N] {
     }
     counts
 }
+
+/// Returns the number of primes smaller than or equal to `x`, π(x).
+///
+/// Unlike [`prime_pi`], which produces a table of counts for every index up to `N`,
+/// this sieves only as many segments of size `MEM` as are needed to reach `x`,
+/// which makes it the function to reach for when `x` is large and only a single count is needed:
+/// memory stays bounded by `MEM` rather than growing with `x`, the same way [`sieve_geq`](crate::sieve_geq)'s
+/// memory use is bounded by its own `MEM` rather than by the requested range.
+///
+/// See also [`Primes::prime_pi`](crate::Primes::prime_pi) for the cache-backed equivalent
+/// when `x` is within an already constructed [`Primes`](crate::Primes) cache.
+///
+/// # Errors
+///
+/// Returns an error, mirroring [`sieve_geq`](crate::sieve_geq), if `x` is larger than or equal to `MEM`^2.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::pi;
+/// const PI_100: Result<u64, const_primes::SieveError> = pi::<11>(100);
+/// assert_eq!(PI_100, Ok(25));
+/// ```
+#[must_use = "the function only returns a new value"]
+pub const fn pi<const MEM: usize>(x: u64) -> Result<u64, SieveError> {
+    let mem_sqr = const {
+        let mem64 = MEM as u64;
+        match mem64.checked_mul(mem64) {
+            Some(mem_sqr) => mem_sqr,
+            None => panic!("`MEM`^2 must fit in a `u64`"),
+        }
+    };
+    let mem64 = MEM as u64;
+
+    if x >= mem_sqr {
+        return Err(SieveError::TooSmallSieveSize);
+    }
+
+    let base_sieve: [bool; MEM] = sieve();
+
+    let mut count: u64 = 0;
+    let base_limit = if x + 1 < mem64 { x + 1 } else { mem64 };
+    let mut i = 0;
+    while (i as u64) < base_limit {
+        if base_sieve[i] {
+            count += 1;
+        }
+        i += 1;
+    }
+
+    if x < mem64 {
+        return Ok(count);
+    }
+
+    let mut upper_limit = mem64;
+    while upper_limit <= x {
+        let Some(next_upper_limit) = upper_limit.checked_add(mem64) else {
+            return Err(SieveError::TotalDoesntFitU64);
+        };
+
+        let segment = match sieve_segment(&base_sieve, next_upper_limit) {
+            Ok(s) => s,
+            Err(_) => return Err(SieveError::TooSmallSieveSize),
+        };
+
+        let limit_in_segment = if x + 1 < next_upper_limit {
+            x + 1 - upper_limit
+        } else {
+            mem64
+        };
+
+        let mut j = 0;
+        while (j as u64) < limit_in_segment {
+            if segment[j] {
+                count += 1;
+            }
+            j += 1;
+        }
+
+        upper_limit = next_upper_limit;
+    }
+
+    Ok(count)
+}
+
+/// Returns the 0-based index of `p` among all primes in increasing order, if `p` is prime.
+///
+/// This is [`pi`]`(p) - 1`, phrased as "which index does this prime have" rather than "how many
+/// primes are there up to this point", which is useful for addressing into prime tables such as
+/// the ones produced by [`primes`](crate::primes).
+///
+/// Returns `Ok(None)` if `p` is not prime, since it then has no index among the primes.
+///
+/// # Errors
+///
+/// Returns an error if `p` is a prime larger than or equal to `MEM`^2, in the same way [`pi`] does.
+/// A `p` that is not prime gives `Ok(None)` whatever its size.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::index_of_prime;
+/// const INDEX_OF_97: Result<Option<usize>, const_primes::SieveError> = index_of_prime::<11>(97);
+/// assert_eq!(INDEX_OF_97, Ok(Some(24))); // 97 is the 25th prime
+/// assert_eq!(index_of_prime::<11>(100), Ok(None)); // 100 is not prime
+/// ```
+#[must_use = "the function only returns a new value"]
+pub const fn index_of_prime<const MEM: usize>(p: u64) -> Result<Option<usize>, SieveError> {
+    if !is_prime(p) {
+        return Ok(None);
+    }
+
+    match pi::<MEM>(p) {
+        Ok(count) => Ok(Some(count as usize - 1)),
+        Err(e) => Err(e),
+    }
+}
+
+/// Returns the number of primes less than or equal to `x`, π(x), computed with
+/// [Legendre's formula](https://en.wikipedia.org/wiki/Meissel%E2%80%93Mertens_constant#Meissel.27s_formula)
+/// instead of by sieving every number up to `x`.
+///
+/// Lists the primes up to `isqrt(x)` with [`sieve`](crate::sieve()), then uses them in Legendre's
+/// recursive φ function and inclusion-exclusion to count the primes up to `x` without visiting
+/// every number below it.
+///
+/// This implementation does not memoize the recursive calls of φ, so its running time grows
+/// quickly with the number of primes up to `isqrt(x)`. It's intended for `x` too large to sieve
+/// directly with [`pi`], but with few enough primes up to its square root for the
+/// recursion to finish quickly.
+///
+/// # Errors
+///
+/// Returns an error if `isqrt(x) >= MEM`, since `MEM` must be large enough for [`sieve`](crate::sieve())
+/// to cover every prime up to `isqrt(x)`.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::prime_pi_legendre;
+/// assert_eq!(prime_pi_legendre::<11>(100), Ok(25));
+/// assert_eq!(prime_pi_legendre::<32>(1000), Ok(168));
+/// ```
+#[must_use = "the function only returns a new value"]
+pub const fn prime_pi_legendre<const MEM: usize>(x: u64) -> Result<u64, SieveError> {
+    if x < 2 {
+        return Ok(0);
+    }
+    if x < 4 {
+        // `pi(2) = 1`, `pi(3) = 2`, and there are no primes `<= isqrt(x) = 1` to build `phi` from.
+        return Ok(x - 1);
+    }
+
+    let sqrt_x = isqrt(x);
+    if sqrt_x as usize >= MEM {
+        return Err(SieveError::TooSmallSieveSize);
+    }
+
+    let base_sieve: [bool; MEM] = sieve();
+
+    let mut small_primes = [0u64; MEM];
+    let mut prime_count = 0;
+    let mut i = 2;
+    while i as u64 <= sqrt_x {
+        if base_sieve[i] {
+            small_primes[prime_count] = i as u64;
+            prime_count += 1;
+        }
+        i += 1;
+    }
+
+    Ok(legendre_phi(x, &small_primes, prime_count) + prime_count as u64 - 1)
+}
+
+/// Returns Legendre's φ(x, a): the number of positive integers `<= x` that are not divisible by
+/// any of the first `a` entries of `small_primes`.
+const fn legendre_phi(x: u64, small_primes: &[u64], a: usize) -> u64 {
+    if a == 0 {
+        return x;
+    }
+    legendre_phi(x, small_primes, a - 1)
+        - legendre_phi(x / small_primes[a - 1], small_primes, a - 1)
+}
+
+/// Returns a rough estimate of π(x), the number of primes less than or equal to `x`, using the
+/// classical `x / ln(x)` approximation from the prime number theorem.
+///
+/// Since `ln` is not available as a `const fn`, `ln(x)` itself is approximated as
+/// `ilog2(x) * ln(2)`, rounding `ln(2)` to 6 decimal digits and carrying it as a fixed-point
+/// integer. This makes the whole function computable in a const context, at the cost of the
+/// estimate being cruder than a floating-point `x / x.ln()` would be.
+///
+/// Returns `0` for `x < 2`.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::approx_prime_pi;
+/// // The true value of `pi(1_000_000)` is 78498.
+/// assert_eq!(approx_prime_pi(1_000_000), 75931);
+/// ```
+#[must_use = "the function only returns a new value"]
+pub const fn approx_prime_pi(x: u64) -> u64 {
+    if x < 2 {
+        return 0;
+    }
+
+    // `ln(2) * 1_000_000`, rounded to the nearest integer.
+    const LN2_MICRO: u128 = 693_147;
+
+    let ln_x_micro = x.ilog2() as u128 * LN2_MICRO;
+
+    ((x as u128 * 1_000_000) / ln_x_micro) as u64
+}
+
+/// Returns both the exact prime count and the [`approx_prime_pi`] estimate for `x`, as
+/// `(exact_pi, estimated_pi)`, so the two can be compared without a second call.
+///
+/// Combines [`pi`] and [`approx_prime_pi`] to make it convenient to study how the
+/// `x / ln(x)` approximation's error grows with `x`.
+///
+/// # Errors
+///
+/// Returns an error if `x` is larger than or equal to `MEM`^2, the same condition under which
+/// [`pi`] errors.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::prime_count_vs_estimate;
+/// const COMPARISON: Result<(u64, u64), const_primes::SieveError> =
+///     prime_count_vs_estimate::<11>(100);
+/// assert_eq!(COMPARISON, Ok((25, 24)));
+/// ```
+#[must_use = "the function only returns a new value"]
+pub const fn prime_count_vs_estimate<const MEM: usize>(x: u64) -> Result<(u64, u64), SieveError> {
+    let exact_pi = match pi::<MEM>(x) {
+        Ok(count) => count,
+        Err(e) => return Err(e),
+    };
+
+    Ok((exact_pi, approx_prime_pi(x)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        approx_prime_pi, index_of_prime, pi, prime_count_vs_estimate, prime_pi_legendre, SieveError,
+    };
+
+    #[test]
+    fn check_pi() {
+        assert_eq!(pi::<11>(100), Ok(25));
+        assert_eq!(pi::<2_000>(1_000_000), Ok(78_498));
+        assert_eq!(pi::<11>(0), Ok(0));
+        assert_eq!(pi::<11>(1), Ok(0));
+        assert_eq!(pi::<11>(2), Ok(1));
+        assert_eq!(pi::<11>(121), Err(SieveError::TooSmallSieveSize));
+    }
+
+    #[test]
+    fn check_index_of_prime() {
+        assert_eq!(index_of_prime::<11>(2), Ok(Some(0)));
+        assert_eq!(index_of_prime::<11>(29), Ok(Some(9)));
+        assert_eq!(index_of_prime::<11>(97), Ok(Some(24)));
+        assert_eq!(index_of_prime::<11>(100), Ok(None));
+        assert_eq!(index_of_prime::<11>(1), Ok(None));
+        assert_eq!(index_of_prime::<11>(0), Ok(None));
+        assert_eq!(
+            index_of_prime::<11>(127),
+            Err(SieveError::TooSmallSieveSize)
+        );
+    }
+
+    #[test]
+    fn check_prime_pi_legendre() {
+        assert_eq!(prime_pi_legendre::<2>(0), Ok(0));
+        assert_eq!(prime_pi_legendre::<2>(1), Ok(0));
+        assert_eq!(prime_pi_legendre::<2>(2), Ok(1));
+        assert_eq!(prime_pi_legendre::<2>(3), Ok(2));
+        assert_eq!(prime_pi_legendre::<3>(4), Ok(2));
+        assert_eq!(prime_pi_legendre::<3>(5), Ok(3));
+        assert_eq!(prime_pi_legendre::<11>(100), Ok(25));
+        assert_eq!(prime_pi_legendre::<32>(1000), Ok(168));
+        for x in 0..500 {
+            assert_eq!(prime_pi_legendre::<23>(x), pi::<23>(x));
+        }
+        assert_eq!(
+            prime_pi_legendre::<9>(100),
+            Err(SieveError::TooSmallSieveSize)
+        );
+    }
+
+    #[test]
+    fn check_approx_prime_pi_and_comparison() {
+        assert_eq!(approx_prime_pi(0), 0);
+        assert_eq!(approx_prime_pi(1), 0);
+        assert_eq!(approx_prime_pi(100), 24);
+        assert_eq!(approx_prime_pi(1_000_000), 75_931);
+
+        assert_eq!(prime_count_vs_estimate::<11>(100), Ok((25, 24)));
+        assert_eq!(
+            prime_count_vs_estimate::<11>(121),
+            Err(SieveError::TooSmallSieveSize)
+        );
+    }
+}
@@ -0,0 +1,454 @@
+//! This module contains const fns that analyze the prime factorization of a `u64` by trial
+//! division, without requiring a [`Primes`](crate::Primes) cache.
+
+use crate::isqrt;
+
+/// Returns Ω(`n`), the number of prime factors of `n` counted with multiplicity.
+///
+/// Does trial division up to `isqrt(n)`. Works for any `u64` and does not require a
+/// [`Primes`](crate::Primes) cache, unlike e.g. [`Primes::prime_factorization`](crate::Primes::prime_factorization).
+///
+/// Returns `0` for `n == 0` and `n == 1`, since neither has any prime factors.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::big_omega_u64;
+/// assert_eq!(big_omega_u64(1), 0);
+/// assert_eq!(big_omega_u64(12), 3); // 12 = 2 * 2 * 3
+/// assert_eq!(big_omega_u64(17), 1);
+/// assert_eq!(big_omega_u64(2_u64.pow(10)), 10);
+/// ```
+#[must_use]
+pub const fn big_omega_u64(mut n: u64) -> u32 {
+    if n < 2 {
+        return 0;
+    }
+
+    let mut count = 0;
+
+    while n % 2 == 0 {
+        n /= 2;
+        count += 1;
+    }
+
+    let mut p = 3;
+    while p <= isqrt(n) {
+        while n % p == 0 {
+            n /= p;
+            count += 1;
+        }
+        p += 2;
+    }
+
+    if n > 1 {
+        count += 1;
+    }
+
+    count
+}
+
+/// Returns the radical of `n`, the product of the distinct primes dividing `n`.
+///
+/// Does trial division up to `isqrt(n)`. Works for any `u64` and does not require a
+/// [`Primes`](crate::Primes) cache.
+///
+/// Returns `1` for `n == 0` and `n == 1`, since neither has any prime factors.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::radical_u64;
+/// assert_eq!(radical_u64(1), 1);
+/// assert_eq!(radical_u64(12), 6); // 12 = 2^2 * 3, radical = 2 * 3
+/// assert_eq!(radical_u64(17), 17);
+/// ```
+#[must_use]
+pub const fn radical_u64(mut n: u64) -> u64 {
+    if n < 2 {
+        return 1;
+    }
+
+    let mut radical = 1;
+
+    if n % 2 == 0 {
+        radical *= 2;
+        while n % 2 == 0 {
+            n /= 2;
+        }
+    }
+
+    let mut p = 3;
+    while p <= isqrt(n) {
+        if n % p == 0 {
+            radical *= p;
+            while n % p == 0 {
+                n /= p;
+            }
+        }
+        p += 2;
+    }
+
+    if n > 1 {
+        radical *= n;
+    }
+
+    radical
+}
+
+/// Returns the squarefree part of `n`, i.e. `n` divided by the largest perfect square dividing it.
+///
+/// Does trial division up to `isqrt(n)`. Works for any `u64` and does not require a
+/// [`Primes`](crate::Primes) cache.
+///
+/// Returns `n` unchanged for `n == 0` and `n == 1`.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::squarefree_part_u64;
+/// assert_eq!(squarefree_part_u64(1), 1);
+/// assert_eq!(squarefree_part_u64(12), 3); // 12 = 2^2 * 3, largest square dividing it is 4
+/// assert_eq!(squarefree_part_u64(18), 2); // 18 = 2 * 3^2
+/// assert_eq!(squarefree_part_u64(17), 17);
+/// ```
+#[must_use]
+pub const fn squarefree_part_u64(mut n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+
+    let mut squarefree_part = 1;
+
+    let mut exponent = 0;
+    while n % 2 == 0 {
+        n /= 2;
+        exponent += 1;
+    }
+    if exponent % 2 == 1 {
+        squarefree_part *= 2;
+    }
+
+    let mut p = 3;
+    while p <= isqrt(n) {
+        let mut exponent = 0;
+        while n % p == 0 {
+            n /= p;
+            exponent += 1;
+        }
+        if exponent % 2 == 1 {
+            squarefree_part *= p;
+        }
+        p += 2;
+    }
+
+    if n > 1 {
+        squarefree_part *= n;
+    }
+
+    squarefree_part
+}
+
+/// Returns whether `n` has no repeated prime factor.
+///
+/// Does trial division up to `isqrt(n)`, stopping as soon as a prime factor is found to divide
+/// `n` more than once. Works for any `u64` and does not require a [`Primes`](crate::Primes) cache.
+///
+/// Returns `false` for `n == 0`, since every square divides it, and `true` for `n == 1`,
+/// which vacuously has no repeated prime factor.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::is_squarefree_u64;
+/// assert!(is_squarefree_u64(1));
+/// assert!(is_squarefree_u64(17));
+/// assert!(is_squarefree_u64(2 * 3 * 5));
+/// assert!(!is_squarefree_u64(12)); // 12 = 2^2 * 3
+/// assert!(!is_squarefree_u64(18)); // 18 = 2 * 3^2
+/// assert!(!is_squarefree_u64(0));
+/// ```
+#[must_use]
+pub const fn is_squarefree_u64(mut n: u64) -> bool {
+    if n == 0 {
+        return false;
+    }
+    if n == 1 {
+        return true;
+    }
+
+    let mut exponent = 0;
+    while n % 2 == 0 {
+        n /= 2;
+        exponent += 1;
+    }
+    if exponent >= 2 {
+        return false;
+    }
+
+    let mut p = 3;
+    while p <= isqrt(n) {
+        let mut exponent = 0;
+        while n % p == 0 {
+            n /= p;
+            exponent += 1;
+        }
+        if exponent >= 2 {
+            return false;
+        }
+        p += 2;
+    }
+
+    true
+}
+
+/// Returns the `p`-adic valuation of `n`, i.e. the exponent of `p` in the prime factorization of `n`.
+///
+/// This assumes that `p` is prime; the result is meaningless otherwise.
+///
+/// `n == 0` is conventionally divisible by `p` infinitely many times, so this returns
+/// [`u32::MAX`] in that case.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::valuation_u64;
+/// assert_eq!(valuation_u64(40, 2), 3); // 40 = 2^3 * 5
+/// assert_eq!(valuation_u64(40, 5), 1);
+/// assert_eq!(valuation_u64(40, 3), 0); // 3 does not divide 40
+/// assert_eq!(valuation_u64(0, 2), u32::MAX);
+/// ```
+#[must_use]
+pub const fn valuation_u64(mut n: u64, p: u64) -> u32 {
+    if n == 0 {
+        return u32::MAX;
+    }
+
+    let mut exponent = 0;
+    while n % p == 0 {
+        n /= p;
+        exponent += 1;
+    }
+    exponent
+}
+
+/// Returns the exponent of the prime `p` in the prime factorization of `n!`, computed with
+/// Legendre's formula: the sum of `floor(n / p^i)` for `i = 1, 2, ...`.
+///
+/// This assumes that `p` is prime; the result is meaningless otherwise.
+///
+/// Used to e.g. find the number of trailing zeros of `n!` in base `p`, or to analyze the
+/// prime factorization of binomial coefficients.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::prime_in_factorial;
+/// // 10! = 3628800 = 2^8 * 3^4 * 5^2 * 7
+/// assert_eq!(prime_in_factorial(2, 10), 8);
+/// assert_eq!(prime_in_factorial(3, 10), 4);
+/// assert_eq!(prime_in_factorial(5, 10), 2);
+/// assert_eq!(prime_in_factorial(7, 10), 1);
+/// assert_eq!(prime_in_factorial(11, 10), 0);
+/// ```
+#[must_use]
+pub const fn prime_in_factorial(p: u64, n: u64) -> u64 {
+    let mut exponent = 0;
+    let mut power = p;
+    while power <= n {
+        exponent += n / power;
+        // Stop before `power * p` could overflow or exceed `n` with no further contribution.
+        if power > n / p {
+            break;
+        }
+        power *= p;
+    }
+    exponent
+}
+
+/// Returns `base^exp`, or [`None`] if that would overflow a [`u64`].
+pub(crate) const fn checked_pow_u64(base: u64, exp: u32) -> Option<u64> {
+    let mut acc: u64 = 1;
+    let mut i = 0;
+    while i < exp {
+        acc = match acc.checked_mul(base) {
+            Some(v) => v,
+            None => return None,
+        };
+        i += 1;
+    }
+    Some(acc)
+}
+
+/// Returns whether `base^exp <= limit`, without overflowing.
+const fn pow_leq(base: u64, exp: u32, limit: u64) -> bool {
+    match checked_pow_u64(base, exp) {
+        Some(v) => v <= limit,
+        None => false,
+    }
+}
+
+/// Returns the largest `r` such that `r^exp <= n`, found by binary search.
+///
+/// `exp` must be at least `2`, and `isqrt(n) + 1` is always a valid upper bound for the search
+/// since an `exp`-th root can never exceed a square root for `n > 1`.
+const fn integer_nth_root(n: u64, exp: u32) -> u64 {
+    if n <= 1 {
+        return n;
+    }
+
+    let mut lo: u64 = 1;
+    let mut hi: u64 = isqrt(n) + 1;
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2 + 1;
+        if pow_leq(mid, exp, n) {
+            lo = mid;
+        } else {
+            hi = mid - 1;
+        }
+    }
+    lo
+}
+
+/// Returns `Some((b, e))` if `n == b^e` for some `b >= 2` and `e >= 2`, and [`None`] otherwise.
+///
+/// Tries every exponent from `ilog2(n)` (the largest exponent `2` could possibly be raised to
+/// without exceeding `n`) down to `2`, finding the corresponding candidate base with
+/// [`isqrt`]-style binary search and checking it exactly. Among all valid `(b, e)` pairs this
+/// returns the one with the largest `e` (equivalently, the smallest `b`), since exponents are
+/// tried from largest to smallest.
+///
+/// This kind of perfect-power check is a standard precondition in primality algorithms such as
+/// [AKS](https://en.wikipedia.org/wiki/AKS_primality_test).
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::is_perfect_power;
+/// assert_eq!(is_perfect_power(64), Some((2, 6))); // 64 = 2^6, the largest valid exponent
+/// assert_eq!(is_perfect_power(36), Some((6, 2))); // 36 = 6^2, there's no larger exponent
+/// assert_eq!(is_perfect_power(125), Some((5, 3))); // 125 = 5^3
+/// assert_eq!(is_perfect_power(30), None); // not a perfect power
+/// assert_eq!(is_perfect_power(2), None); // no valid exponent exists
+/// ```
+#[must_use]
+pub const fn is_perfect_power(n: u64) -> Option<(u64, u32)> {
+    if n < 4 {
+        return None;
+    }
+
+    let mut exponent = n.ilog2();
+    while exponent >= 2 {
+        let base = integer_nth_root(n, exponent);
+        if base >= 2 {
+            if let Some(power) = checked_pow_u64(base, exponent) {
+                if power == n {
+                    return Some((base, exponent));
+                }
+            }
+        }
+        exponent -= 1;
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        big_omega_u64, is_perfect_power, is_squarefree_u64, prime_in_factorial, radical_u64,
+        squarefree_part_u64, valuation_u64,
+    };
+
+    #[test]
+    fn check_big_omega_u64() {
+        assert_eq!(big_omega_u64(0), 0);
+        assert_eq!(big_omega_u64(1), 0);
+        assert_eq!(big_omega_u64(2), 1);
+        assert_eq!(big_omega_u64(12), 3);
+        assert_eq!(big_omega_u64(17), 1);
+        assert_eq!(big_omega_u64(2 * 2 * 2 * 3 * 5), 5);
+        assert_eq!(big_omega_u64(18_446_744_073_709_551_557), 1); // a prime close to u64::MAX
+        assert_eq!(big_omega_u64(u64::MAX), 7); // u64::MAX = 3 * 5 * 17 * 257 * 641 * 65537 * 6700417
+    }
+
+    #[test]
+    fn check_radical_u64() {
+        assert_eq!(radical_u64(0), 1);
+        assert_eq!(radical_u64(1), 1);
+        assert_eq!(radical_u64(2), 2);
+        assert_eq!(radical_u64(12), 6);
+        assert_eq!(radical_u64(17), 17);
+        assert_eq!(radical_u64(2 * 2 * 2 * 3 * 5), 2 * 3 * 5);
+        assert_eq!(radical_u64(u64::MAX), u64::MAX); // u64::MAX is squarefree
+    }
+
+    #[test]
+    fn check_squarefree_part_u64() {
+        assert_eq!(squarefree_part_u64(0), 0);
+        assert_eq!(squarefree_part_u64(1), 1);
+        assert_eq!(squarefree_part_u64(4), 1);
+        assert_eq!(squarefree_part_u64(12), 3);
+        assert_eq!(squarefree_part_u64(18), 2);
+        assert_eq!(squarefree_part_u64(17), 17);
+        assert_eq!(squarefree_part_u64(2 * 2 * 3 * 3 * 5), 5);
+        assert_eq!(squarefree_part_u64(u64::MAX), u64::MAX); // u64::MAX is squarefree
+    }
+
+    #[test]
+    fn check_is_squarefree_u64() {
+        assert!(!is_squarefree_u64(0));
+        assert!(is_squarefree_u64(1));
+        assert!(is_squarefree_u64(2));
+        assert!(!is_squarefree_u64(4));
+        assert!(!is_squarefree_u64(12)); // 2^2 * 3
+        assert!(!is_squarefree_u64(18)); // 2 * 3^2
+        assert!(is_squarefree_u64(17));
+        assert!(is_squarefree_u64(2 * 3 * 5));
+        assert!(is_squarefree_u64(u64::MAX));
+
+        for n in 1..1_000u64 {
+            assert_eq!(is_squarefree_u64(n), squarefree_part_u64(n) == n);
+        }
+    }
+
+    #[test]
+    fn check_valuation_u64() {
+        assert_eq!(valuation_u64(40, 2), 3);
+        assert_eq!(valuation_u64(40, 5), 1);
+        assert_eq!(valuation_u64(40, 3), 0);
+        assert_eq!(valuation_u64(1, 2), 0);
+        assert_eq!(valuation_u64(0, 2), u32::MAX);
+        assert_eq!(valuation_u64(2_u64.pow(20), 2), 20);
+    }
+
+    #[test]
+    fn check_prime_in_factorial() {
+        assert_eq!(prime_in_factorial(2, 10), 8);
+        assert_eq!(prime_in_factorial(3, 10), 4);
+        assert_eq!(prime_in_factorial(5, 10), 2);
+        assert_eq!(prime_in_factorial(7, 10), 1);
+        assert_eq!(prime_in_factorial(11, 10), 0);
+        assert_eq!(prime_in_factorial(2, 0), 0);
+        assert_eq!(prime_in_factorial(2, u64::MAX), u64::MAX - 64);
+    }
+
+    #[test]
+    fn check_is_perfect_power() {
+        assert_eq!(is_perfect_power(0), None);
+        assert_eq!(is_perfect_power(1), None);
+        assert_eq!(is_perfect_power(2), None);
+        assert_eq!(is_perfect_power(3), None);
+        assert_eq!(is_perfect_power(4), Some((2, 2)));
+        assert_eq!(is_perfect_power(8), Some((2, 3)));
+        assert_eq!(is_perfect_power(9), Some((3, 2)));
+        assert_eq!(is_perfect_power(16), Some((2, 4)));
+        assert_eq!(is_perfect_power(27), Some((3, 3)));
+        assert_eq!(is_perfect_power(36), Some((6, 2)));
+        assert_eq!(is_perfect_power(64), Some((2, 6))); // picks the largest exponent, 6, not 2 or 3
+        assert_eq!(is_perfect_power(125), Some((5, 3)));
+        assert_eq!(is_perfect_power(30), None);
+        assert_eq!(is_perfect_power(u64::MAX), None);
+        assert_eq!(is_perfect_power(1 << 63), Some((2, 63)));
+    }
+}
@@ -2,7 +2,11 @@
 
 use core::fmt;
 
-use crate::{sieve, sieve::sieve_segment, Underlying};
+use crate::{
+    gcd, is_prime, sieve,
+    sieve::{sieve_geq, sieve_segment},
+    ArraySection, Primes, Underlying,
+};
 
 /// Returns the `N` first prime numbers.
 ///
@@ -99,6 +103,347 @@ pub const fn primes<const N: usize>() -> [Underlying; N] {
     primes
 }
 
+/// Returns the `N` first prime numbers in descending order.
+///
+/// Equivalent to reversing the result of [`primes`], which is useful for table consumers
+/// such as trial division that try the largest factors first, since `Iterator::rev` isn't
+/// available in a const context.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::primes_desc;
+/// const PRIMES: [u32; 10] = primes_desc();
+/// assert_eq!(PRIMES, [29, 23, 19, 17, 13, 11, 7, 5, 3, 2]);
+/// ```
+#[must_use = "the function only returns a new value"]
+pub const fn primes_desc<const N: usize>() -> [Underlying; N] {
+    let ascending: [Underlying; N] = primes();
+    let mut descending = [0; N];
+    let mut i = 0;
+    while i < N {
+        descending[i] = ascending[N - 1 - i];
+        i += 1;
+    }
+    descending
+}
+
+/// Returns the product of the first `N` primes, the `N`-th [primorial](https://en.wikipedia.org/wiki/Primorial),
+/// or [`None`] if that product overflows a [`u64`], which happens starting at `N == 16`
+/// (the 16th primorial, `2 * 3 * ... * 53`, is the first to exceed [`u64::MAX`]).
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::checked_primorial;
+/// assert_eq!(checked_primorial::<0>(), Some(1));
+/// assert_eq!(checked_primorial::<4>(), Some(2 * 3 * 5 * 7));
+/// assert_eq!(checked_primorial::<16>(), None);
+/// ```
+#[must_use = "the function only returns a new value"]
+pub const fn checked_primorial<const N: usize>() -> Option<u64> {
+    let primes: [Underlying; N] = primes();
+
+    let mut product: u64 = 1;
+    let mut i = 0;
+    while i < N {
+        product = match product.checked_mul(primes[i] as u64) {
+            Some(p) => p,
+            None => return None,
+        };
+        i += 1;
+    }
+    Some(product)
+}
+
+/// Returns the product of the first `N` primes, the `N`-th [primorial](https://en.wikipedia.org/wiki/Primorial).
+///
+/// # Panics
+///
+/// Panics if the product overflows a [`u64`], which happens starting at `N == 16`. Use
+/// [`checked_primorial`] to detect that case instead of panicking.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::primorial;
+/// const PRIMORIAL: u64 = primorial::<4>();
+/// assert_eq!(PRIMORIAL, 2 * 3 * 5 * 7);
+/// assert_eq!(primorial::<0>(), 1);
+/// ```
+#[must_use = "the function only returns a new value"]
+pub const fn primorial<const N: usize>() -> u64 {
+    match checked_primorial::<N>() {
+        Some(product) => product,
+        None => panic!("the primorial of the first `N` primes overflowed a `u64`"),
+    }
+}
+
+// Generates a `const fn` with the same segmented-sieve body as `primes`, but that collects its
+// output into `[$ty; N]` instead of `[Underlying; N]`. Kept as a macro rather than a generic
+// function because Rust has no `as $ty` cast for a type parameter, and const trait methods
+// (which could stand in for the cast) are not stable at this crate's MSRV.
+macro_rules! primes_of_type {
+    ($(#[$attr:meta])* $name:ident, $ty:ty) => {
+        $(#[$attr])*
+        #[must_use = "the function only returns a new value"]
+        pub const fn $name<const N: usize>() -> [$ty; N] {
+            if N <= 1 {
+                return [2; N];
+            } else if N == 2 {
+                let mut primes = [0; N];
+                primes[0] = 2;
+                primes[1] = 3;
+                return primes;
+            }
+
+            let mut primes = [0; N];
+            let mut prime_count = 0;
+
+            let mut sieve: [bool; N] = sieve();
+
+            let mut number = 0;
+            while number < N {
+                if sieve[number] {
+                    primes[prime_count] = number as $ty;
+                    prime_count += 1;
+                }
+                number += 1;
+            }
+
+            let mut low = N - 1;
+            let mut high = 2 * N - 1;
+            'generate: while prime_count < N {
+                sieve = [true; N];
+                let mut i = 0;
+
+                while i < prime_count {
+                    let prime = primes[i] as usize;
+
+                    let mut composite = (low / prime) * prime;
+                    if composite < low {
+                        composite += prime;
+                    }
+
+                    while composite < high {
+                        sieve[composite - low] = false;
+                        composite += prime;
+                    }
+
+                    i += 1;
+                }
+
+                i = low;
+                while i < high {
+                    if sieve[i - low] {
+                        primes[prime_count] = i as $ty;
+                        prime_count += 1;
+                        if prime_count >= N {
+                            break 'generate;
+                        }
+                    }
+                    i += 1;
+                }
+
+                low += N;
+                high += N;
+            }
+
+            primes
+        }
+    };
+}
+
+primes_of_type!(
+    /// Returns the `N` first prime numbers as `[u16; N]`.
+    ///
+    /// Identical to [`primes`], but for `u16` instead of [`Underlying`]. Like [`primes`],
+    /// the conversion from the internal sieve index is a plain `as u16` cast, so a prime
+    /// that doesn't fit in a `u16` is silently truncated rather than reported as an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::primes_u16;
+    /// const PRIMES: [u16; 10] = primes_u16();
+    /// assert_eq!(PRIMES, [2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    /// ```
+    primes_u16,
+    u16
+);
+
+primes_of_type!(
+    /// Returns the `N` first prime numbers as `[u64; N]`.
+    ///
+    /// Identical to [`primes`], but for `u64` instead of [`Underlying`], for callers who want
+    /// primes larger than `u32::MAX` without going through the segmented [`primes_geq`] family.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::primes_u64;
+    /// const PRIMES: [u64; 10] = primes_u64();
+    /// assert_eq!(PRIMES, [2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    /// ```
+    primes_u64,
+    u64
+);
+
+primes_of_type!(
+    /// Returns the `N` first prime numbers as `[u128; N]`.
+    ///
+    /// Identical to [`primes`], but for `u128` instead of [`Underlying`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::primes_u128;
+    /// const PRIMES: [u128; 10] = primes_u128();
+    /// assert_eq!(PRIMES, [2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    /// ```
+    primes_u128,
+    u128
+);
+
+primes_of_type!(
+    /// Returns the `N` first prime numbers as `[usize; N]`.
+    ///
+    /// Identical to [`primes`], but for `usize` instead of [`Underlying`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use const_primes::primes_usize;
+    /// const PRIMES: [usize; 10] = primes_usize();
+    /// assert_eq!(PRIMES, [2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    /// ```
+    primes_usize,
+    usize
+);
+
+mod private {
+    /// Seals [`super::PrimeInt`] so it can only be implemented by this crate.
+    pub trait Sealed {}
+    impl Sealed for u16 {}
+    impl Sealed for u32 {}
+    impl Sealed for u64 {}
+    impl Sealed for u128 {}
+    impl Sealed for usize {}
+}
+
+/// A primitive integer type that [`primes_typed`] can generate the first `N` primes into.
+///
+/// Sealed: implemented only for `u16`, `u32`, `u64`, `u128` and `usize`, the types this crate
+/// has a generation routine for, and can't be implemented outside this crate.
+pub trait PrimeInt: private::Sealed + Copy {
+    /// Returns the `N` first prime numbers as `[Self; N]`.
+    #[doc(hidden)]
+    fn generate<const N: usize>() -> [Self; N];
+}
+
+macro_rules! impl_prime_int {
+    ($ty:ty, $generator:ident) => {
+        impl PrimeInt for $ty {
+            fn generate<const N: usize>() -> [Self; N] {
+                $generator::<N>()
+            }
+        }
+    };
+}
+
+impl_prime_int!(u16, primes_u16);
+impl_prime_int!(u32, primes);
+impl_prime_int!(u64, primes_u64);
+impl_prime_int!(u128, primes_u128);
+impl_prime_int!(usize, primes_usize);
+
+/// Returns the `N` first prime numbers as `[T; N]`, for any of the integer types that implement
+/// [`PrimeInt`] (`u16`, `u32`, `u64`, `u128` and `usize`).
+///
+/// Lets generic code pick its prime width with a type parameter instead of calling [`primes`],
+/// [`primes_u16`], [`primes_u64`], [`primes_u128`] or [`primes_usize`] directly.
+///
+/// Trait methods can't be `const fn` at this crate's MSRV (const traits are unstable), so unlike
+/// those functions, `primes_typed` itself isn't `const`. Call the concrete function for your type
+/// when you need a `const` binding.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::primes_typed;
+/// let first_five: [u64; 5] = primes_typed::<u64, 5>();
+/// assert_eq!(first_five, [2, 3, 5, 7, 11]);
+/// ```
+#[must_use = "the function only returns a new value"]
+pub fn primes_typed<T: PrimeInt, const N: usize>() -> [T; N] {
+    T::generate::<N>()
+}
+
+/// Builds a [`Primes`] cache of size `N` and verifies that it covers `x`.
+///
+/// This combines the common pattern of guessing an `N`, constructing a [`Primes`] cache of that
+/// size, and manually checking whether [`last`](Primes::last) reaches `x`, into a single call.
+///
+/// # Errors
+///
+/// Returns [`GenerationError::OutOfPrimes`] if the `N` first primes don't reach `x`,
+/// i.e. if [`last`](Primes::last) is smaller than `x`. In that case `N` needs to be
+/// increased to cover `x`; [`pi`](crate::pi) can help estimate by how much.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::{primes_covering, GenerationError, Primes};
+/// const CACHE: Result<Primes<5>, GenerationError> = primes_covering(10);
+/// assert_eq!(CACHE.unwrap().as_array(), &[2, 3, 5, 7, 11]);
+///
+/// const TOO_SMALL: Result<Primes<5>, GenerationError> = primes_covering(12);
+/// assert_eq!(TOO_SMALL, Err(GenerationError::OutOfPrimes));
+/// ```
+#[must_use = "the function only returns a new value"]
+pub const fn primes_covering<const N: usize>(x: u32) -> Result<Primes<N>, GenerationError> {
+    let cache = Primes::<N>::new();
+    if *cache.last() >= x {
+        Ok(cache)
+    } else {
+        Err(GenerationError::OutOfPrimes)
+    }
+}
+
+/// A single prime number with a stable, `#[repr(C)]` layout.
+///
+/// Plain `[u32; N]` arrays are already C-compatible, but this named, `repr(C)` wrapper
+/// documents that intent and leaves room to add fields later without breaking the layout
+/// of code that hands prime tables to C or GPU code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct PrimeEntry {
+    /// The value of the prime.
+    pub value: Underlying,
+}
+
+/// Returns the `N` first prime numbers as [`PrimeEntry`] values with a stable, `#[repr(C)]` layout,
+/// for handing off to C or GPU code.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::{primes_repr_c, PrimeEntry};
+/// const PRIMES: [PrimeEntry; 3] = primes_repr_c();
+/// assert_eq!(PRIMES, [PrimeEntry { value: 2 }, PrimeEntry { value: 3 }, PrimeEntry { value: 5 }]);
+/// ```
+#[must_use = "the function only returns a new value"]
+pub const fn primes_repr_c<const N: usize>() -> [PrimeEntry; N] {
+    let values: [Underlying; N] = primes();
+    let mut entries = [PrimeEntry { value: 0 }; N];
+    let mut i = 0;
+    while i < N {
+        entries[i] = PrimeEntry { value: values[i] };
+        i += 1;
+    }
+    entries
+}
+
 /// Returns the `N` largest primes less than `upper_limit`.
 ///
 /// This function uses a segmented sieve of size `MEM` for computation,
@@ -236,6 +581,102 @@ pub const fn primes_lt<const N: usize, const MEM: usize>(
     Ok(primes)
 }
 
+/// Returns the largest primes less than `upper_limit` that fit in an array of size `N`,
+/// along with how many were found, without erroring if fewer than `N` primes exist below the limit.
+///
+/// This is the same algorithm as [`primes_lt`], except that running out of primes
+/// below `upper_limit` is not treated as an error: whatever primes were found are
+/// returned instead, left-aligned in the resulting [`ArraySection`].
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::primes_lt_filled;
+/// // There are only 4 primes below 10.
+/// let (primes, count) = primes_lt_filled::<10, 10>(10);
+/// assert_eq!(count, 4);
+/// assert_eq!(primes.as_slice(), &[2, 3, 5, 7]);
+/// ```
+///
+/// Invalid limits (`upper_limit` <= 2, or larger than `MEM`^2) also just produce zero found primes,
+/// rather than an error, since there is no error variant to report through:
+///
+/// ```
+/// # use const_primes::primes_lt_filled;
+/// let (primes, count) = primes_lt_filled::<5, 5>(2);
+/// assert_eq!(count, 0);
+/// assert!(primes.is_empty());
+/// ```
+#[must_use = "the function only returns a new value and does not modify its input"]
+pub const fn primes_lt_filled<const N: usize, const MEM: usize>(
+    mut upper_limit: u64,
+) -> (ArraySection<u64, N>, usize) {
+    const { assert!(MEM >= N, "`MEM` must be at least as large as `N`") }
+
+    let mem_sqr = const {
+        let mem64 = MEM as u64;
+        match mem64.checked_mul(mem64) {
+            Some(mem_sqr) => mem_sqr,
+            None => panic!("`MEM`^2 must fit in a u64"),
+        }
+    };
+
+    if N == 0 || upper_limit <= 2 || upper_limit > mem_sqr {
+        return (ArraySection::new([0; N], 0), 0);
+    }
+
+    let mut primes: [u64; N] = [0; N];
+
+    // This will be used to sieve all upper ranges.
+    let base_sieve: [bool; MEM] = sieve();
+
+    let mut total_primes_found: usize = 0;
+    'generate: while total_primes_found < N {
+        // This is the smallest prime we have found so far.
+        let mut smallest_found_prime = primes[N - 1 - total_primes_found];
+        // Sieve for primes in the segment.
+        let (offset, upper_sieve) = match sieve_segment(&base_sieve, upper_limit) {
+            Ok(res) => (0, res),
+            // The segment was larger than there are numbers left to sieve, just use the base sieve
+            Err(_) => ((MEM as u64 - upper_limit) as usize, base_sieve),
+        };
+
+        let mut i: usize = 0;
+        while i < MEM - offset {
+            // Iterate backwards through the upper sieve.
+            if upper_sieve[MEM - 1 - i - offset] {
+                smallest_found_prime = upper_limit - 1 - i as u64;
+                // Write every found prime to the primes array.
+                primes[N - 1 - total_primes_found] = smallest_found_prime;
+                total_primes_found += 1;
+                if total_primes_found >= N {
+                    // If we have found enough primes we stop sieving.
+                    break 'generate;
+                }
+            }
+            i += 1;
+        }
+        upper_limit = smallest_found_prime;
+        if upper_limit <= 2 && total_primes_found < N {
+            // Ran out of primes; stop with whatever was found instead of erroring.
+            break 'generate;
+        }
+    }
+
+    // The primes found so far are right-aligned in `primes`; shift them to the front.
+    let mut left_aligned = [0; N];
+    let mut i = 0;
+    while i < total_primes_found {
+        left_aligned[i] = primes[N - total_primes_found + i];
+        i += 1;
+    }
+
+    (
+        ArraySection::new(left_aligned, total_primes_found),
+        total_primes_found,
+    )
+}
+
 /// Generate arrays of large prime numbers without having to store all primes
 /// from 2 and up in the result, and thus potentially the binary.
 ///
@@ -247,6 +688,8 @@ pub const fn primes_lt<const N: usize, const MEM: usize>(
 /// Estimates the sieve size as `isqrt(upper_limit) + 1` for [`primes_lt`]
 /// and as `isqrt(lower_limit) + 1 + N` for [`primes_geq`].
 /// This may overestimate the memory requirement for `primes_geq`.
+/// The exact value it chooses can be inspected ahead of time through
+/// [`chosen_mem_lt`](crate::chosen_mem_lt) and [`chosen_mem_geq`](crate::chosen_mem_geq).
 ///
 /// # Example
 ///
@@ -273,22 +716,10 @@ pub const fn primes_lt<const N: usize, const MEM: usize>(
 #[macro_export]
 macro_rules! primes_segment {
     ($n:expr; < $lim:expr) => {
-        $crate::primes_lt::<
-            { $n },
-            {
-                let mem: u64 = { $lim };
-                $crate::isqrt(mem) as ::core::primitive::usize + 1
-            },
-        >({ $lim })
+        $crate::primes_lt::<{ $n }, { $crate::chosen_mem_lt({ $lim }) }>({ $lim })
     };
     ($n:expr; >= $lim:expr) => {
-        $crate::primes_geq::<
-            { $n },
-            {
-                let mem: u64 = { $lim };
-                $crate::isqrt(mem) as ::core::primitive::usize + 1 + { $n }
-            },
-        >({ $lim })
+        $crate::primes_geq::<{ $n }, { $crate::chosen_mem_geq({ $lim }, { $n }) }>({ $lim })
     };
 }
 
@@ -435,53 +866,652 @@ pub const fn primes_geq<const N: usize, const MEM: usize>(
     Ok(primes)
 }
 
-/// The error returned by [`primes_lt`] and [`primes_geq`] if the input
-/// is invalid or does not work to produce the requested primes.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(
-    feature = "rkyv",
-    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
-)]
-pub enum GenerationError {
-    /// The limit was larger than or equal to `MEM^2`.
-    TooSmallSieveSize,
-    /// The limit was smaller than or equal to 2.
-    TooSmallLimit,
-    /// Encountered a number larger than or equal to `MEM`^2.
-    SieveOverrun(u64),
-    /// Ran out of primes.
-    OutOfPrimes,
-}
+/// Returns primes number `skip` through `skip + N - 1` (0-indexed) in the sequence of all primes.
+///
+/// Lets a worker in a distributed computation generate its own disjoint block of the prime
+/// sequence, e.g. `primes_from_index::<1000, MEM>(4000)` for the block that a fifth worker out
+/// of many would own, without any worker needing to generate the primes that came before its block.
+///
+/// Uses a segmented sieve of size `MEM` for computation, in the same way as [`primes_geq`].
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::{primes_from_index, GenerationError};
+/// // Primes number 4 through 7 (0-indexed): the 5th, 6th, 7th and 8th primes.
+/// const PRIMES: Result<[u64; 4], GenerationError> = primes_from_index::<4, 10>(4);
+/// assert_eq!(PRIMES, Ok([11, 13, 17, 19]));
+/// # Ok::<(), GenerationError>(())
+/// ```
+///
+/// # Errors
+///
+/// Only primes smaller than `MEM^2` can be generated, so if the sieve encounters a number
+/// larger than that it results in an error:
+///
+/// ```
+/// # use const_primes::{primes_from_index, GenerationError};
+/// const PRIMES: Result<[u64; 2], GenerationError> = primes_from_index::<2, 2>(3);
+/// // The sieve is unable to determine the prime status of 4,
+/// // since that is the same or larger than `MEM`^2.
+/// assert_eq!(PRIMES, Err(GenerationError::SieveOverrun(4)));
+/// ```
+///
+/// It is a compile error if `MEM` is smaller than `N`, or if `MEM`^2 does not fit in a `u64`:
+///
+/// ```compile_fail
+/// # use const_primes::{primes_from_index, GenerationError};
+/// const TOO_SMALL_MEM: Result<[u64; 5], GenerationError> = primes_from_index::<5, 2>(0);
+/// ```
+///
+/// ```compile_fail
+/// # use const_primes::{primes_from_index, GenerationError};
+/// const TOO_BIG_MEM: Result<[u64; 10], GenerationError> = primes_from_index::<10, 1_000_000_000_000>(0);
+/// ```
+#[must_use = "the function only returns a new value and does not modify its input"]
+pub const fn primes_from_index<const N: usize, const MEM: usize>(
+    skip: usize,
+) -> Result<[u64; N], GenerationError> {
+    const { assert!(MEM >= N, "`MEM` must be at least as large as `N`") }
 
-impl fmt::Display for GenerationError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            Self::TooSmallSieveSize => write!(
-                f,
-                "the limit was larger than `MEM`^2"
-            ),
-            Self::TooSmallLimit => write!(
-                f,
-                "the limit was smaller than or equal to 2"
-            ),
-            Self::SieveOverrun(number) => write!(
-                f,
-                "encountered the number {number} which would have needed `MEM` to be at least {} to sieve", crate::integer_math::isqrt(*number) + 1
-            ),
-            Self::OutOfPrimes => write!(f, "ran out of primes before the array was filled"),
+    let (mem64, mem_sqr) = const {
+        let mem64 = MEM as u64;
+        match mem64.checked_mul(mem64) {
+            Some(mem_sqr) => (mem64, mem_sqr),
+            None => panic!("`MEM`^2 must fit in a `u64`"),
         }
+    };
+
+    if N == 0 {
+        return Ok([0; N]);
     }
-}
 
-impl core::error::Error for GenerationError {}
+    let mut primes = [0; N];
+    let mut skipped_primes = 0;
+    let mut total_found_primes = 0;
+    let mut largest_found_prime = 0;
+    let base_sieve: [bool; MEM] = sieve();
+    let mut sieve_limit = 2;
+    'generate: while total_found_primes < N {
+        let upper_sieve = match sieve_segment(&base_sieve, sieve_limit + mem64) {
+            Ok(res) => res,
+            Err(_) => panic!("can not happen since we set upper limit to mem + nonzero stuff"),
+        };
 
-#[cfg(test)]
-mod test {
+        let mut i = 0;
+        while i < MEM {
+            if upper_sieve[i] {
+                largest_found_prime = sieve_limit + i as u64;
+
+                // We can not know whether this is actually a prime since
+                // the base sieve contains no information
+                // about numbers larger than or equal to `MEM`^2.
+                if largest_found_prime >= mem_sqr {
+                    return Err(GenerationError::SieveOverrun(largest_found_prime));
+                }
+
+                if skipped_primes < skip {
+                    skipped_primes += 1;
+                } else {
+                    primes[total_found_primes] = largest_found_prime;
+                    total_found_primes += 1;
+                    if total_found_primes >= N {
+                        // We've found enough primes.
+                        break 'generate;
+                    }
+                }
+            }
+            i += 1;
+        }
+        sieve_limit = largest_found_prime + 1;
+    }
+
+    Ok(primes)
+}
+
+/// Returns the `N` first superprimes: primes whose (1-based) index in the sequence of all
+/// primes is itself prime.
+///
+/// The first few are 3, 5, 11, 17, 31, ..., the primes at positions 2, 3, 5, 7, 11, ...
+/// This is [OEIS A006450](https://oeis.org/A006450).
+///
+/// Generates the prime sequence with a segmented sieve of size `MEM`, in the same way as
+/// [`primes_from_index`], and keeps every prime whose running 1-based index is prime according
+/// to [`is_prime`].
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::{superprimes, GenerationError};
+/// const SUPERPRIMES: Result<[u64; 5], GenerationError> = superprimes::<5, 20>();
+/// assert_eq!(SUPERPRIMES, Ok([3, 5, 11, 17, 31]));
+/// ```
+///
+/// # Errors
+///
+/// Only primes smaller than `MEM^2` can be generated, so if the sieve encounters a number
+/// larger than that it results in an error:
+///
+/// ```
+/// # use const_primes::{superprimes, GenerationError};
+/// const SUPERPRIMES: Result<[u64; 5], GenerationError> = superprimes::<5, 5>();
+/// assert_eq!(SUPERPRIMES, Err(GenerationError::SieveOverrun(25)));
+/// ```
+///
+/// It is a compile error if `MEM`^2 does not fit in a `u64`:
+///
+/// ```compile_fail
+/// # use const_primes::{superprimes, GenerationError};
+/// const TOO_BIG_MEM: Result<[u64; 10], GenerationError> = superprimes::<10, 1_000_000_000_000>();
+/// ```
+#[must_use = "the function only returns a new value"]
+pub const fn superprimes<const N: usize, const MEM: usize>() -> Result<[u64; N], GenerationError> {
+    let (mem64, mem_sqr) = const {
+        let mem64 = MEM as u64;
+        match mem64.checked_mul(mem64) {
+            Some(mem_sqr) => (mem64, mem_sqr),
+            None => panic!("`MEM`^2 must fit in a `u64`"),
+        }
+    };
+
+    if N == 0 {
+        return Ok([0; N]);
+    }
+
+    let mut superprimes = [0; N];
+    let mut prime_index: u64 = 0;
+    let mut total_found_superprimes = 0;
+    let mut largest_found_prime = 0;
+    let base_sieve: [bool; MEM] = sieve();
+    let mut sieve_limit = 2;
+    'generate: while total_found_superprimes < N {
+        let upper_sieve = match sieve_segment(&base_sieve, sieve_limit + mem64) {
+            Ok(res) => res,
+            Err(_) => panic!("can not happen since we set upper limit to mem + nonzero stuff"),
+        };
+
+        let mut i = 0;
+        while i < MEM {
+            if upper_sieve[i] {
+                largest_found_prime = sieve_limit + i as u64;
+
+                // We can not know whether this is actually a prime since
+                // the base sieve contains no information
+                // about numbers larger than or equal to `MEM`^2.
+                if largest_found_prime >= mem_sqr {
+                    return Err(GenerationError::SieveOverrun(largest_found_prime));
+                }
+
+                prime_index += 1;
+                if is_prime(prime_index) {
+                    superprimes[total_found_superprimes] = largest_found_prime;
+                    total_found_superprimes += 1;
+                    if total_found_superprimes >= N {
+                        // We've found enough superprimes.
+                        break 'generate;
+                    }
+                }
+            }
+            i += 1;
+        }
+        sieve_limit = largest_found_prime + 1;
+    }
+
+    Ok(superprimes)
+}
+
+/// Returns the first `N` twin prime pairs, smaller member first.
+///
+/// Uses a [segmented sieve of Eratosthenes](https://en.wikipedia.org/wiki/Sieve_of_Eratosthenes#Segmented_sieve)
+/// of size `MEM` to search for the pairs, the same strategy as [`superprimes`].
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::{twin_primes, GenerationError};
+/// const TWIN_PRIMES: Result<[(u64, u64); 5], GenerationError> = twin_primes::<5, 50>();
+/// assert_eq!(TWIN_PRIMES, Ok([(3, 5), (5, 7), (11, 13), (17, 19), (29, 31)]));
+/// ```
+///
+/// # Errors
+///
+/// Only pairs smaller than `MEM^2` can be found, so if the sieve encounters a number
+/// larger than that it results in an error:
+///
+/// ```
+/// # use const_primes::{twin_primes, GenerationError};
+/// const TWIN_PRIMES: Result<[(u64, u64); 5], GenerationError> = twin_primes::<5, 5>();
+/// assert_eq!(TWIN_PRIMES, Err(GenerationError::SieveOverrun(25)));
+/// ```
+///
+/// It is a compile error if `MEM`^2 does not fit in a `u64`:
+///
+/// ```compile_fail
+/// # use const_primes::{twin_primes, GenerationError};
+/// const TOO_BIG_MEM: Result<[(u64, u64); 5], GenerationError> = twin_primes::<5, 1_000_000_000_000>();
+/// ```
+#[must_use = "the function only returns a new value"]
+pub const fn twin_primes<const N: usize, const MEM: usize>(
+) -> Result<[(u64, u64); N], GenerationError> {
+    let (mem64, mem_sqr) = const {
+        let mem64 = MEM as u64;
+        match mem64.checked_mul(mem64) {
+            Some(mem_sqr) => (mem64, mem_sqr),
+            None => panic!("`MEM`^2 must fit in a `u64`"),
+        }
+    };
+
+    if N == 0 {
+        return Ok([(0, 0); N]);
+    }
+
+    let mut twin_primes = [(0, 0); N];
+    let mut pair_count = 0;
+    let mut previous_prime: Option<u64> = None;
+    let base_sieve: [bool; MEM] = sieve();
+    let mut sieve_limit = 2;
+    'generate: while pair_count < N {
+        let upper_sieve = match sieve_segment(&base_sieve, sieve_limit + mem64) {
+            Ok(res) => res,
+            Err(_) => panic!("can not happen since we set upper limit to mem + nonzero stuff"),
+        };
+
+        let mut i = 0;
+        while i < MEM {
+            if upper_sieve[i] {
+                let prime = sieve_limit + i as u64;
+
+                // We can not know whether this is actually a prime since
+                // the base sieve contains no information
+                // about numbers larger than or equal to `MEM`^2.
+                if prime >= mem_sqr {
+                    return Err(GenerationError::SieveOverrun(prime));
+                }
+
+                if let Some(prev) = previous_prime {
+                    if prime - prev == 2 {
+                        twin_primes[pair_count] = (prev, prime);
+                        pair_count += 1;
+                        if pair_count >= N {
+                            break 'generate;
+                        }
+                    }
+                }
+                previous_prime = Some(prime);
+            }
+            i += 1;
+        }
+        sieve_limit += mem64;
+    }
+
+    Ok(twin_primes)
+}
+
+/// Returns the `N` gaps between the first `N + 1` primes, i.e. `primes[i + 1] - primes[i]`.
+///
+/// Uses a [segmented sieve of Eratosthenes](https://en.wikipedia.org/wiki/Sieve_of_Eratosthenes#Segmented_sieve)
+/// of size `MEM` to search for the primes, the same strategy as [`superprimes`] and [`twin_primes`].
+///
+/// A signature of just `prime_gaps<const N: usize>() -> [u32; N]`, generating its primes with
+/// `primes::<{ N + 1 }>()` internally, isn't expressible on stable Rust: using a generic
+/// parameter in an arithmetic expression that sizes another generic (`N + 1`) requires the
+/// unstable `generic_const_exprs` feature, which this crate's MSRV doesn't have. The `MEM`
+/// parameter here plays the same role it does in [`superprimes`] and [`twin_primes`].
+///
+/// [`Primes::gaps`](crate::Primes::gaps) computes the same thing from an already-built cache,
+/// without the extra `MEM` parameter, since a [`Primes`] already knows how many primes it holds.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::{prime_gaps, GenerationError};
+/// const GAPS: Result<[u32; 7], GenerationError> = prime_gaps::<7, 50>();
+/// assert_eq!(GAPS, Ok([1, 2, 2, 4, 2, 4, 2]));
+/// ```
+///
+/// # Errors
+///
+/// Only gaps between primes smaller than `MEM^2` can be computed, so if the sieve encounters a
+/// number larger than that it results in an error:
+///
+/// ```
+/// # use const_primes::{prime_gaps, GenerationError};
+/// const GAPS: Result<[u32; 7], GenerationError> = prime_gaps::<7, 4>();
+/// assert_eq!(GAPS, Err(GenerationError::SieveOverrun(17)));
+/// ```
+///
+/// It is a compile error if `MEM`^2 does not fit in a `u64`:
+///
+/// ```compile_fail
+/// # use const_primes::{prime_gaps, GenerationError};
+/// const TOO_BIG_MEM: Result<[u32; 7], GenerationError> = prime_gaps::<7, 1_000_000_000_000>();
+/// ```
+#[must_use = "the function only returns a new value"]
+pub const fn prime_gaps<const N: usize, const MEM: usize>() -> Result<[u32; N], GenerationError> {
+    let (mem64, mem_sqr) = const {
+        let mem64 = MEM as u64;
+        match mem64.checked_mul(mem64) {
+            Some(mem_sqr) => (mem64, mem_sqr),
+            None => panic!("`MEM`^2 must fit in a `u64`"),
+        }
+    };
+
+    if N == 0 {
+        return Ok([0; N]);
+    }
+
+    let mut gaps = [0; N];
+    let mut gap_count = 0;
+    let mut previous_prime: Option<u64> = None;
+    let base_sieve: [bool; MEM] = sieve();
+    let mut sieve_limit = 2;
+    'generate: while gap_count < N {
+        let upper_sieve = match sieve_segment(&base_sieve, sieve_limit + mem64) {
+            Ok(res) => res,
+            Err(_) => panic!("can not happen since we set upper limit to mem + nonzero stuff"),
+        };
+
+        let mut i = 0;
+        while i < MEM {
+            if upper_sieve[i] {
+                let prime = sieve_limit + i as u64;
+
+                // We can not know whether this is actually a prime since
+                // the base sieve contains no information
+                // about numbers larger than or equal to `MEM`^2.
+                if prime >= mem_sqr {
+                    return Err(GenerationError::SieveOverrun(prime));
+                }
+
+                if let Some(prev) = previous_prime {
+                    gaps[gap_count] = (prime - prev) as Underlying;
+                    gap_count += 1;
+                    if gap_count >= N {
+                        break 'generate;
+                    }
+                }
+                previous_prime = Some(prime);
+            }
+            i += 1;
+        }
+        sieve_limit += mem64;
+    }
+
+    Ok(gaps)
+}
+
+/// Returns the primes among the `N` smallest numbers greater than or equal to `lower_limit`.
+///
+/// Unlike [`primes_geq`], which returns a fixed *count* of primes regardless of how much of the
+/// number line that takes, this returns whichever primes lie in the fixed *window*
+/// `[lower_limit, lower_limit + N)`, which may be fewer than `N`.
+///
+/// Uses a sieve of size `MEM` during evaluation; `MEM`^2 must be larger than `lower_limit + N`.
+///
+/// If `lower_limit` or `MEM` are invalid in the same way that would make [`sieve_geq`] return an
+/// error, this returns an empty [`ArraySection`] instead, since there is no error variant to report through.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::primes_geq_window;
+/// // The primes among the 10 numbers starting at 100.
+/// let window = primes_geq_window::<10, 15>(100);
+/// assert_eq!(window.as_slice(), &[101, 103, 107, 109]);
+/// ```
+#[must_use = "the function only returns a new value and does not modify its input"]
+pub const fn primes_geq_window<const N: usize, const MEM: usize>(
+    lower_limit: u64,
+) -> ArraySection<u64, N> {
+    let prime_status: [bool; N] = match sieve_geq::<N, MEM>(lower_limit) {
+        Ok(status) => status,
+        Err(_) => return ArraySection::new([0; N], 0),
+    };
+
+    let mut window = [0; N];
+    let mut count = 0;
+    let mut i = 0;
+    while i < N {
+        if prime_status[i] {
+            window[count] = lower_limit + i as u64;
+            count += 1;
+        }
+        i += 1;
+    }
+
+    ArraySection::new(window, count)
+}
+
+/// Computes the primes in the range `[a, b)`, automatically sizing the window and the sieve.
+///
+/// Calls [`primes_geq_window`], using `b - a` as the window size and automatically computing the
+/// memory requirement of the sieve, so you don't have to pick both generic parameters by hand.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::primes_between;
+/// let primes = primes_between!(100, 120);
+/// assert_eq!(primes.as_slice(), &[101, 103, 107, 109, 113]);
+/// ```
+#[macro_export]
+macro_rules! primes_between {
+    ($a:expr, $b:expr) => {
+        $crate::primes_geq_window::<
+            { ({ $b } - { $a }) as ::core::primitive::usize },
+            {
+                let mem: u64 = { $b };
+                $crate::isqrt(mem) as ::core::primitive::usize
+                    + 1
+                    + ({ ({ $b } - { $a }) as ::core::primitive::usize })
+            },
+        >({ $a })
+    };
+}
+
+/// Returns the `N` smallest primes congruent to `residue` modulo `modulus`.
+///
+/// This is a targeted [Dirichlet progression](https://en.wikipedia.org/wiki/Dirichlet%27s_theorem_on_arithmetic_progressions)
+/// generator, useful for e.g. primes congruent to 1 mod 4.
+///
+/// Uses a segmented sieve of size `MEM` for computation, in the same way as [`primes_geq`].
+///
+/// If `gcd(residue, modulus) > 1`, every number congruent to `residue` modulo `modulus` is a
+/// multiple of that gcd, so the only number in the progression that can possibly be prime is the
+/// gcd itself.
+///
+/// If fewer than `N` such primes are found before the search would need to look past `MEM`^2,
+/// the search stops and whatever was found is returned instead of erroring, left-aligned in the
+/// resulting [`ArraySection`], the same way [`primes_lt_filled`] does.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::primes_congruent;
+/// // The 4 smallest primes congruent to 1 modulo 4.
+/// let primes = primes_congruent::<4, 10>(1, 4);
+/// assert_eq!(primes.as_slice(), &[5, 13, 17, 29]);
+/// ```
+///
+/// If `residue` and `modulus` share a common factor, the only prime that can appear is that
+/// factor itself:
+///
+/// ```
+/// # use const_primes::primes_congruent;
+/// let primes = primes_congruent::<4, 10>(2, 4);
+/// assert_eq!(primes.as_slice(), &[2]);
+///
+/// let primes = primes_congruent::<4, 10>(0, 4);
+/// assert!(primes.is_empty()); // 4 is not prime
+/// ```
+#[must_use = "the function only returns a new value and does not modify its input"]
+pub const fn primes_congruent<const N: usize, const MEM: usize>(
+    residue: u64,
+    modulus: u64,
+) -> ArraySection<u64, N> {
+    if N == 0 || modulus == 0 {
+        return ArraySection::new([0; N], 0);
+    }
+
+    let residue = residue % modulus;
+
+    let d = gcd(residue, modulus);
+    if d > 1 {
+        let mut found = [0; N];
+        let count = if d % modulus == residue && is_prime(d) {
+            found[0] = d;
+            1
+        } else {
+            0
+        };
+        return ArraySection::new(found, count);
+    }
+
+    let (mem64, mem_sqr) = match (MEM as u64).checked_mul(MEM as u64) {
+        Some(mem_sqr) => (MEM as u64, mem_sqr),
+        None => return ArraySection::new([0; N], 0),
+    };
+
+    let mut found = [0; N];
+    let mut count = 0;
+
+    let base_sieve: [bool; MEM] = sieve();
+
+    // The smallest candidate that's both `>= 2` and congruent to `residue` modulo `modulus`.
+    let mut sieve_limit = residue;
+    while sieve_limit < 2 {
+        sieve_limit += modulus;
+    }
+
+    'generate: while count < N && sieve_limit < mem_sqr {
+        let upper_sieve = match sieve_segment(&base_sieve, sieve_limit + mem64) {
+            Ok(res) => res,
+            Err(_) => panic!("can not happen since we set upper limit to mem + nonzero stuff"),
+        };
+
+        let mut i = 0;
+        while i < MEM {
+            let candidate = sieve_limit + i as u64;
+            if candidate >= mem_sqr {
+                break 'generate;
+            }
+            if upper_sieve[i] && candidate % modulus == residue {
+                found[count] = candidate;
+                count += 1;
+                if count >= N {
+                    break 'generate;
+                }
+            }
+            i += 1;
+        }
+
+        sieve_limit += mem64;
+    }
+
+    ArraySection::new(found, count)
+}
+
+/// The error returned by [`primes_lt`] and [`primes_geq`] if the input
+/// is invalid or does not work to produce the requested primes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub enum GenerationError {
+    /// The limit was larger than or equal to `MEM^2`.
+    TooSmallSieveSize,
+    /// The limit was smaller than or equal to 2.
+    TooSmallLimit,
+    /// Encountered a number larger than or equal to `MEM`^2.
+    SieveOverrun(u64),
+    /// Ran out of primes.
+    OutOfPrimes,
+}
+
+impl fmt::Display for GenerationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::TooSmallSieveSize => write!(
+                f,
+                "the limit was larger than `MEM`^2"
+            ),
+            Self::TooSmallLimit => write!(
+                f,
+                "the limit was smaller than or equal to 2"
+            ),
+            Self::SieveOverrun(number) => write!(
+                f,
+                "encountered the number {number} which would have needed `MEM` to be at least {} to sieve", crate::integer_math::isqrt(*number) + 1
+            ),
+            Self::OutOfPrimes => write!(f, "ran out of primes before the array was filled"),
+        }
+    }
+}
+
+impl core::error::Error for GenerationError {}
+
+#[cfg(test)]
+mod test {
     use crate::is_prime;
 
     use super::*;
 
+    #[test]
+    fn test_generation_error_is_ordered() {
+        let mut errors = [
+            GenerationError::OutOfPrimes,
+            GenerationError::TooSmallLimit,
+            GenerationError::SieveOverrun(4),
+            GenerationError::TooSmallSieveSize,
+            GenerationError::SieveOverrun(2),
+        ];
+        errors.sort();
+        assert_eq!(
+            errors,
+            [
+                GenerationError::TooSmallSieveSize,
+                GenerationError::TooSmallLimit,
+                GenerationError::SieveOverrun(2),
+                GenerationError::SieveOverrun(4),
+                GenerationError::OutOfPrimes,
+            ]
+        );
+    }
+
+    #[test]
+    fn check_primes_covering() {
+        const CACHE: Result<Primes<5>, GenerationError> = primes_covering(10);
+        assert_eq!(CACHE.unwrap().as_array(), &[2, 3, 5, 7, 11]);
+
+        const TOO_SMALL: Result<Primes<5>, GenerationError> = primes_covering(12);
+        assert_eq!(TOO_SMALL, Err(GenerationError::OutOfPrimes));
+    }
+
+    #[test]
+    fn check_primes_desc() {
+        const PRIMES: [Underlying; 10] = primes_desc();
+        assert_eq!(PRIMES, [29, 23, 19, 17, 13, 11, 7, 5, 3, 2]);
+        const EMPTY: [Underlying; 0] = primes_desc();
+        assert_eq!(EMPTY, [] as [Underlying; 0]);
+        const ONE: [Underlying; 1] = primes_desc();
+        assert_eq!(ONE, [2]);
+    }
+
+    #[test]
+    fn check_primorial() {
+        assert_eq!(checked_primorial::<0>(), Some(1));
+        assert_eq!(checked_primorial::<1>(), Some(2));
+        assert_eq!(checked_primorial::<4>(), Some(2 * 3 * 5 * 7));
+        assert_eq!(checked_primorial::<15>(), Some(614_889_782_588_491_410));
+        assert_eq!(checked_primorial::<16>(), None); // overflows a `u64`
+
+        const PRIMORIAL: u64 = primorial::<4>();
+        assert_eq!(PRIMORIAL, 210);
+        assert_eq!(primorial::<0>(), 1);
+    }
+
     #[test]
     fn sanity_check_primes_geq() {
         {
@@ -508,6 +1538,58 @@ mod test {
         assert_eq!(primes_geq::<2, 2>(3), Err(GenerationError::SieveOverrun(4)));
     }
 
+    #[test]
+    fn sanity_check_primes_from_index() {
+        assert_eq!(primes_from_index::<5, 5>(0), Ok([2, 3, 5, 7, 11]));
+        assert_eq!(primes_from_index::<4, 10>(4), Ok([11, 13, 17, 19]));
+        assert_eq!(primes_from_index::<0, 0>(10), Ok([]));
+
+        // Consecutive blocks should tile the same sequence that `primes` produces directly.
+        const WHOLE: [Underlying; 10] = primes();
+        let first_half = primes_from_index::<5, 10>(0).unwrap();
+        let second_half = primes_from_index::<5, 10>(5).unwrap();
+        for i in 0..5 {
+            assert_eq!(first_half[i], WHOLE[i] as u64);
+            assert_eq!(second_half[i], WHOLE[5 + i] as u64);
+        }
+
+        assert_eq!(
+            primes_from_index::<2, 2>(3),
+            Err(GenerationError::SieveOverrun(4))
+        );
+    }
+
+    #[test]
+    fn sanity_check_superprimes() {
+        // Positions 2, 3, 5, 7, 11 in the prime sequence hold 3, 5, 11, 17, 31.
+        assert_eq!(superprimes::<5, 20>(), Ok([3, 5, 11, 17, 31]));
+        assert_eq!(superprimes::<0, 2>(), Ok([]));
+        assert_eq!(
+            superprimes::<5, 5>(),
+            Err(GenerationError::SieveOverrun(25))
+        );
+    }
+
+    #[test]
+    fn sanity_check_twin_primes() {
+        assert_eq!(
+            twin_primes::<5, 50>(),
+            Ok([(3, 5), (5, 7), (11, 13), (17, 19), (29, 31)])
+        );
+        assert_eq!(twin_primes::<0, 2>(), Ok([]));
+        assert_eq!(
+            twin_primes::<5, 5>(),
+            Err(GenerationError::SieveOverrun(25))
+        );
+    }
+
+    #[test]
+    fn sanity_check_prime_gaps() {
+        assert_eq!(prime_gaps::<7, 50>(), Ok([1, 2, 2, 4, 2, 4, 2]));
+        assert_eq!(prime_gaps::<0, 2>(), Ok([]));
+        assert_eq!(prime_gaps::<7, 4>(), Err(GenerationError::SieveOverrun(17)));
+    }
+
     #[test]
     fn sanity_check_primes_lt() {
         {
@@ -531,6 +1613,28 @@ mod test {
         assert_eq!(primes_lt::<3, 5>(4), Err(GenerationError::OutOfPrimes));
     }
 
+    #[test]
+    fn check_primes_between() {
+        let primes = primes_between!(100, 120);
+        assert_eq!(primes.as_slice(), &[101, 103, 107, 109, 113]);
+        let none = primes_between!(24, 28);
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn check_primes_congruent() {
+        assert_eq!(primes_congruent::<4, 10>(1, 4).as_slice(), &[5, 13, 17, 29]);
+        assert_eq!(primes_congruent::<4, 10>(3, 4).as_slice(), &[3, 7, 11, 19]);
+        assert_eq!(primes_congruent::<4, 10>(2, 4).as_slice(), &[2]);
+        assert!(primes_congruent::<1, 10>(0, 4).is_empty());
+        assert_eq!(
+            primes_congruent::<5, 10>(1, 10).as_slice(),
+            &[11, 31, 41, 61, 71]
+        );
+        assert!(primes_congruent::<3, 3>(0, 0).is_empty());
+        assert!(primes_congruent::<0, 3>(1, 4).is_empty());
+    }
+
     #[test]
     fn check_primes_segment() {
         const P_GEQ: Result<[u64; 10], GenerationError> = primes_segment!(10; >= 1000);
@@ -542,4 +1646,25 @@ mod test {
         );
         assert_eq!(P_LT, Ok([937, 941, 947, 953, 967, 971, 977, 983, 991, 997]));
     }
+
+    #[test]
+    fn sanity_check_primes_typed() {
+        const P_U16: [u16; 10] = primes_u16();
+        const P_U64: [u64; 10] = primes_u64();
+        const P_U128: [u128; 10] = primes_u128();
+        const P_USIZE: [usize; 10] = primes_usize();
+        const P_U32: [Underlying; 10] = primes();
+
+        for i in 0..10 {
+            assert_eq!(P_U16[i] as Underlying, P_U32[i]);
+            assert_eq!(P_U64[i] as Underlying, P_U32[i]);
+            assert_eq!(P_U128[i] as Underlying, P_U32[i]);
+            assert_eq!(P_USIZE[i] as Underlying, P_U32[i]);
+        }
+
+        let generic: [u64; 10] = primes_typed::<u64, 10>();
+        assert_eq!(generic, P_U64);
+        let generic: [u16; 10] = primes_typed::<u16, 10>();
+        assert_eq!(generic, P_U16);
+    }
 }
@@ -32,6 +32,169 @@ pub const fn isqrt(n: u64) -> u64 {
     }
 }
 
+/// Returns the largest integer smaller than or equal to the cube root of `n`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use const_primes::icbrt;
+/// const ICBRT26: u64 = icbrt(26);
+/// const ICBRT27: u64 = icbrt(27);
+/// const ICBRT63: u64 = icbrt(63);
+///
+/// assert_eq!(ICBRT26, 2);
+/// assert_eq!(ICBRT27, 3);
+/// assert_eq!(ICBRT63, 3);
+/// ```
+#[must_use]
+pub const fn icbrt(n: u64) -> u64 {
+    if n <= 1 {
+        n
+    } else {
+        let mut x0 = u64::pow(2, n.ilog2() / 3 + 1);
+        let mut x1 = (2 * x0 + n / (x0 * x0)) / 3;
+        while x1 < x0 {
+            x0 = x1;
+            x1 = (2 * x0 + n / (x0 * x0)) / 3;
+        }
+        x0
+    }
+}
+
+/// Returns the sieve size that [`sieve_segment!`](crate::sieve_segment) and
+/// [`primes_segment!`](crate::primes_segment) choose for their `< limit` arm.
+///
+/// Exposed so that the memory a segment macro will use can be inspected or logged
+/// without invoking the macro itself.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::chosen_mem_lt;
+/// assert_eq!(chosen_mem_lt(100), 11);
+/// ```
+#[must_use]
+pub const fn chosen_mem_lt(limit: u64) -> usize {
+    isqrt(limit) as usize + 1
+}
+
+/// Returns the sieve size that [`sieve_segment!`](crate::sieve_segment) and
+/// [`primes_segment!`](crate::primes_segment) choose for their `>= limit` arm, when `n`
+/// results are requested.
+///
+/// Exposed so that the memory a segment macro will use can be inspected or logged
+/// without invoking the macro itself.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::chosen_mem_geq;
+/// assert_eq!(chosen_mem_geq(100, 5), 16);
+/// ```
+#[must_use]
+pub const fn chosen_mem_geq(limit: u64, n: usize) -> usize {
+    isqrt(limit) as usize + 1 + n
+}
+
+/// Returns the greatest common divisor of `a` and `b`, using the Euclidean algorithm.
+///
+/// `gcd(0, 0)` is `0`.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::gcd;
+/// assert_eq!(gcd(48, 18), 6);
+/// assert_eq!(gcd(17, 5), 1);
+/// assert_eq!(gcd(0, 7), 7);
+/// ```
+#[must_use]
+pub const fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+/// Returns the least common multiple of `a` and `b`.
+///
+/// `lcm(0, n)` and `lcm(n, 0)` are `0`, since `0` isn't a multiple of anything but itself.
+///
+/// Divides by the gcd before multiplying, rather than the other way around, so that
+/// `a * b / gcd(a, b)` can't overflow a `u64` when the mathematical result would fit but the
+/// intermediate product wouldn't.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::lcm;
+/// assert_eq!(lcm(4, 6), 12);
+/// assert_eq!(lcm(21, 6), 42);
+/// assert_eq!(lcm(0, 7), 0);
+/// assert_eq!(lcm(5, 5), 5);
+/// ```
+#[must_use]
+pub const fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    (a / gcd(a, b)) * b
+}
+
+/// Returns the multiplicative inverse of `a` modulo `modulus`, if it exists.
+///
+/// Found with the [extended Euclidean algorithm](https://en.wikipedia.org/wiki/Extended_Euclidean_algorithm),
+/// which also yields `gcd(a, modulus)` for free: the inverse exists exactly when that gcd is `1`.
+///
+/// Returns `None` if `modulus <= 1`, or if `gcd(a, modulus) != 1` and `a` is therefore not
+/// invertible modulo `modulus`.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::mod_inverse;
+/// assert_eq!(mod_inverse(3, 7), Some(5)); // 3 * 5 = 15 ≡ 1 (mod 7)
+/// assert_eq!(mod_inverse(4, 8), None); // gcd(4, 8) = 4
+/// ```
+#[must_use]
+pub const fn mod_inverse(a: u64, modulus: u64) -> Option<u64> {
+    if modulus <= 1 {
+        return None;
+    }
+
+    let modulus = modulus as i128;
+    let mut old_r = a as i128 % modulus;
+    let mut r = modulus;
+    let mut old_s: i128 = 1;
+    let mut s: i128 = 0;
+
+    while r != 0 {
+        let quotient = old_r / r;
+        let new_r = old_r - quotient * r;
+        old_r = r;
+        r = new_r;
+
+        let new_s = old_s - quotient * s;
+        old_s = s;
+        s = new_s;
+    }
+
+    if old_r != 1 {
+        return None;
+    }
+
+    let inverse = old_s % modulus;
+    Some(if inverse < 0 {
+        (inverse + modulus) as u64
+    } else {
+        inverse as u64
+    })
+}
+
 #[cfg(not(feature = "fast_test"))]
 /// Calculates (`base` ^ `exp`) mod `modulo` without overflow.
 #[must_use]
@@ -58,6 +221,52 @@ pub const fn mod_mul(a: u64, b: u64, modulo: u64) -> u64 {
     ((a as u128 * b as u128) % modulo as u128) as u64
 }
 
+/// Calculates (`a` + `b`) mod `modulo` without overflow, for `a, b < modulo`.
+const fn add_mod_u128(a: u128, b: u128, modulo: u128) -> u128 {
+    if a >= modulo - b {
+        a - (modulo - b)
+    } else {
+        a + b
+    }
+}
+
+/// Calculates (`a` * `b`) mod `modulo` without overflow.
+///
+/// There is no integer type twice as wide as [`u128`] to borrow the trick in [`mod_mul`]
+/// from, so this instead uses binary ("double and add") multiplication, reducing modulo
+/// `modulo` after every doubling.
+#[must_use]
+pub const fn mod_mul_u128(mut a: u128, mut b: u128, modulo: u128) -> u128 {
+    let mut result: u128 = 0;
+    a %= modulo;
+    while b > 0 {
+        if b & 1 == 1 {
+            result = add_mod_u128(result, a, modulo);
+        }
+        a = add_mod_u128(a, a, modulo);
+        b >>= 1;
+    }
+    result
+}
+
+/// Calculates (`base` ^ `exp`) mod `modulo` without overflow.
+#[must_use]
+pub const fn mod_pow_u128(mut base: u128, mut exp: u128, modulo: u128) -> u128 {
+    let mut res = 1;
+
+    base %= modulo;
+
+    while exp > 0 {
+        if exp % 2 == 1 {
+            res = mod_mul_u128(res, base, modulo);
+        }
+        base = mod_mul_u128(base, base, modulo);
+        exp >>= 1;
+    }
+
+    res
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -74,4 +283,78 @@ mod test {
         assert_eq!(isqrt(u64::MAX - 1), 4294967295);
         assert_eq!(isqrt(u64::MAX), 4294967295);
     }
+
+    #[test]
+    fn check_icbrt() {
+        for x in 0..1_000_000 {
+            assert_eq!(icbrt(x), (x as f64).cbrt().floor() as u64);
+        }
+        assert_eq!(icbrt(26), 2);
+        assert_eq!(icbrt(27), 3);
+        assert_eq!(icbrt(28), 3);
+        assert_eq!(icbrt(u64::MAX), 2_642_245);
+        assert_eq!(icbrt(2_642_245 * 2_642_245 * 2_642_245), 2_642_245);
+    }
+
+    #[test]
+    fn check_gcd() {
+        assert_eq!(gcd(48, 18), 6);
+        assert_eq!(gcd(18, 48), 6);
+        assert_eq!(gcd(17, 5), 1);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(gcd(7, 0), 7);
+        assert_eq!(gcd(0, 0), 0);
+    }
+
+    #[test]
+    fn check_lcm() {
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(21, 6), 42);
+        assert_eq!(lcm(0, 7), 0);
+        assert_eq!(lcm(7, 0), 0);
+        assert_eq!(lcm(0, 0), 0);
+        assert_eq!(lcm(5, 5), 5);
+        assert_eq!(lcm(17, 5), 85); // coprime
+        assert_eq!(lcm(gcd(48, 18), 1), gcd(48, 18));
+    }
+
+    #[test]
+    fn check_mod_inverse() {
+        assert_eq!(mod_inverse(3, 7), Some(5));
+        assert_eq!(mod_inverse(10, 17), Some(12));
+        assert_eq!(mod_inverse(1, 1), None);
+        assert_eq!(mod_inverse(5, 1), None);
+        assert_eq!(mod_inverse(4, 8), None); // gcd(4, 8) = 4
+        assert_eq!(mod_inverse(0, 5), None); // gcd(0, 5) = 5
+        assert_eq!(mod_inverse(6, 9), None); // gcd(6, 9) = 3
+
+        for a in 1..100u64 {
+            if let Some(inverse) = mod_inverse(a, 101) {
+                assert_eq!((a * inverse) % 101, 1);
+            }
+        }
+    }
+
+    #[test]
+    fn check_chosen_mem() {
+        assert_eq!(chosen_mem_lt(100), 11);
+        assert_eq!(chosen_mem_lt(1_000_000), 1001);
+        assert_eq!(chosen_mem_geq(100, 5), 16);
+        assert_eq!(chosen_mem_geq(1_000_000, 25), 1026);
+    }
+
+    #[test]
+    fn check_mod_mul_and_mod_pow_u128() {
+        assert_eq!(mod_mul_u128(123_456, 654_321, 1_000_003), 611_039);
+        assert_eq!(
+            mod_pow_u128(2, 128, u64::MAX as u128 + 1_000_003),
+            1_000_004_000_004
+        );
+
+        // Close to `u128::MAX` on both operands: a plain `a * b` would overflow.
+        let modulo = u128::MAX - 58;
+        let a = u128::MAX - 3;
+        let b = u128::MAX - 5;
+        assert_eq!(mod_mul_u128(a, b, modulo), 2_915);
+    }
 }
@@ -3,7 +3,7 @@
 //! This crate lets you for example pre-compute prime numbers at compile time, store them in the binary, and use them later for related computations,
 //! or check whether a number is prime in a const function.
 //!
-//! `no_std` compatible when the `serde` feature is disabled.
+//! `no_std` compatible when the `serde` and `rayon` features are disabled.
 //!
 //! This version of the crate supports Rust versions 1.81.0 and up, while versions 0.8.7 and older support Rust versions 1.67.1 and up.
 //!
@@ -109,21 +109,51 @@
 // This is used since there is currently no way to be generic over types that can do arithmetic at compile time.
 type Underlying = u32;
 
+mod array_section;
+mod bit_sieve;
 pub mod cache;
 mod check;
 mod count;
+mod factor;
 mod generate;
 mod integer_math;
+mod modular;
+#[cfg(feature = "rayon")]
+mod parallel;
 mod search;
 mod sieve;
+pub mod stats;
 
+pub use array_section::{ArraySection, ArraySectionIntoIter};
+pub use bit_sieve::BitSieve;
 pub use cache::Primes;
-pub use check::is_prime;
-pub use count::prime_pi;
-pub use generate::{primes, primes_geq, primes_lt, GenerationError};
-pub use integer_math::isqrt;
-pub use search::{next_prime, previous_prime};
-pub use sieve::{sieve, sieve_geq, sieve_lt, SieveError};
+pub use check::{
+    is_emirp, is_factorial_prime, is_fermat_prime, is_prime, is_prime_signed, is_prime_u128,
+    is_prime_u32, is_prime_with_trial_bound, is_primorial_prime, is_strong_probable_prime,
+    prime_certificate, verify_primes,
+};
+pub use count::{
+    approx_prime_pi, index_of_prime, pi, prime_count_vs_estimate, prime_pi, prime_pi_legendre,
+};
+pub use factor::{
+    big_omega_u64, is_perfect_power, is_squarefree_u64, prime_in_factorial, radical_u64,
+    squarefree_part_u64, valuation_u64,
+};
+pub use generate::{
+    checked_primorial, prime_gaps, primes, primes_congruent, primes_covering, primes_desc,
+    primes_from_index, primes_geq, primes_geq_window, primes_lt, primes_lt_filled, primes_repr_c,
+    primes_typed, primes_u128, primes_u16, primes_u64, primes_usize, primorial, superprimes,
+    twin_primes, GenerationError, PrimeEntry, PrimeInt,
+};
+pub use integer_math::{chosen_mem_geq, chosen_mem_lt, gcd, icbrt, isqrt, lcm, mod_inverse};
+pub use modular::{discrete_log, jacobi, kronecker, lucas_u_v, multiplicative_order};
+#[cfg(feature = "rayon")]
+pub use parallel::are_prime_par;
+pub use search::{next_prime, previous_prime, PrimeWalker};
+pub use sieve::{
+    find_last_digit_run, first_gap_geq, is_prime_via_sieve, nearest_primes, nth_twin_prime,
+    prime_constellations, sieve, sieve_geq, sieve_lt, sieve_wheel, ulam_spiral, SieveError,
+};
 
 #[cfg(test)]
 mod test {
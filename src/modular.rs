@@ -0,0 +1,451 @@
+//! This module contains const fns for modular arithmetic that build on the
+//! lower-level primitives in [`crate::integer_math`].
+
+use crate::{gcd, integer_math::mod_mul_u128};
+
+/// Returns the multiplicative order of `a` modulo `n`: the smallest `k > 0` such that
+/// `a^k ≡ 1 (mod n)`.
+///
+/// Returns `None` if `n < 2` or `gcd(a, n) != 1`, since `a` then has no multiplicative order
+/// modulo `n`.
+///
+/// Finds the order by repeated multiplication rather than by factoring `φ(n)`, since `n` is an
+/// arbitrary `u64` and this function does not have access to a [`Primes`](crate::Primes) cache.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::multiplicative_order;
+/// // 3 has order 6 modulo 7: 3, 2, 6, 4, 5, 1.
+/// assert_eq!(multiplicative_order(3, 7), Some(6));
+/// assert_eq!(multiplicative_order(2, 4), None); // gcd(2, 4) = 2
+/// ```
+#[must_use]
+pub const fn multiplicative_order(a: u64, n: u64) -> Option<u64> {
+    if n < 2 || gcd(a, n) != 1 {
+        return None;
+    }
+
+    let a_mod = a % n;
+    let mut current = a_mod;
+    let mut k: u64 = 1;
+    while current != 1 {
+        current = ((current as u128 * a_mod as u128) % n as u128) as u64;
+        k += 1;
+    }
+    Some(k)
+}
+
+/// Returns the smallest `x` such that `base^x ≡ target (mod modulo)`, using the
+/// [baby-step giant-step](https://en.wikipedia.org/wiki/Baby-step_giant-step) algorithm.
+///
+/// `M` bounds the work and storage this function uses: it is guaranteed to find `x` if one
+/// exists and `x < M`^2. Since this crate can't allocate, `M` must be supplied by the caller
+/// instead of being derived as `isqrt(modulo) + 1`, similarly to the `MEM` parameter of
+/// [`sieve_lt`](crate::sieve_lt) and friends.
+///
+/// Returns `None` if `modulo <= 1`, if `gcd(base, modulo) != 1` (`base` is then not invertible
+/// modulo `modulo`, so it generates no well-defined discrete logarithm), or if no `x < M`^2
+/// satisfies the congruence.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::discrete_log;
+/// // 3^5 = 243 ≡ 5 (mod 7)
+/// assert_eq!(discrete_log::<3>(3, 5, 7), Some(5));
+/// assert_eq!(discrete_log::<3>(2, 5, 7), None); // 2 only generates {1, 2, 4} mod 7
+/// ```
+#[must_use]
+pub const fn discrete_log<const M: usize>(base: u64, target: u64, modulo: u64) -> Option<u64> {
+    if modulo <= 1 {
+        return None;
+    }
+
+    let base_mod = base % modulo;
+    let target_mod = target % modulo;
+
+    if gcd(base_mod, modulo) != 1 {
+        return None;
+    }
+
+    if target_mod == 1 % modulo {
+        return Some(0);
+    }
+
+    // `baby[j]` holds `target_mod * base_mod^j mod modulo`.
+    let mut baby = [0u64; M];
+    let mut power = 1u64;
+    let mut j = 0;
+    while j < M {
+        baby[j] = ((target_mod as u128 * power as u128) % modulo as u128) as u64;
+        power = ((power as u128 * base_mod as u128) % modulo as u128) as u64;
+        j += 1;
+    }
+
+    // `power` is now `base_mod^M mod modulo`, the giant-step factor.
+    let giant = power;
+
+    let mut current = 1u64;
+    let mut i = 1;
+    while i <= M {
+        current = ((current as u128 * giant as u128) % modulo as u128) as u64;
+
+        // If several `j` match, the largest one gives the smallest `x` for this `i`,
+        // and since the `x` ranges of successive `i` don't overlap, it's the global smallest.
+        let mut best_j: Option<usize> = None;
+        let mut j = 0;
+        while j < M {
+            if baby[j] == current {
+                best_j = Some(j);
+            }
+            j += 1;
+        }
+
+        if let Some(j) = best_j {
+            return Some((i * M - j) as u64);
+        }
+
+        i += 1;
+    }
+
+    None
+}
+
+/// Computes the [Kronecker symbol](https://en.wikipedia.org/wiki/Kronecker_symbol) `(a/n)`,
+/// a generalization of the [Jacobi symbol](https://en.wikipedia.org/wiki/Jacobi_symbol) to all
+/// integers `n`, including even and negative ones.
+///
+/// This is used for example to select parameters for Lucas sequences (as in the BPSW primality
+/// test) and in the theory of binary quadratic forms, both of which need the symbol evaluated at
+/// moduli that are not necessarily odd and positive.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::kronecker;
+/// assert_eq!(kronecker(1001, 9907), -1);
+/// assert_eq!(kronecker(3, 8), -1); // (3/8) = (3/2)^3
+/// assert_eq!(kronecker(5, -4), 1); // (5/-4) = (5/-1) * (5/2)^2
+/// assert_eq!(kronecker(2, 9), 1);
+/// assert_eq!(kronecker(1, 0), 1);
+/// assert_eq!(kronecker(2, 0), 0);
+/// ```
+#[must_use]
+pub const fn kronecker(a: i64, n: i64) -> i8 {
+    if n == 0 {
+        return if a == 1 || a == -1 { 1 } else { 0 };
+    }
+
+    let mut result: i8 = 1;
+    let mut n = n;
+
+    if n < 0 {
+        n = -n;
+        if a < 0 {
+            result = -result;
+        }
+    }
+
+    let mut twos = 0u32;
+    while n % 2 == 0 {
+        n /= 2;
+        twos += 1;
+    }
+
+    if twos > 0 {
+        if a % 2 == 0 {
+            // (a/2) = 0 when a is even, and that factor is raised to a positive power.
+            return 0;
+        }
+        if twos % 2 == 1 {
+            let a_mod8 = a.rem_euclid(8);
+            if a_mod8 == 3 || a_mod8 == 5 {
+                result = -result;
+            }
+        }
+    }
+
+    if n == 1 {
+        return result;
+    }
+
+    // `n` is now odd and greater than 1: compute the Jacobi symbol `(a/n)` iteratively using
+    // quadratic reciprocity, mirroring the classic Jacobi symbol algorithm.
+    let mut a = a.rem_euclid(n);
+    while a != 0 {
+        while a % 2 == 0 {
+            a /= 2;
+            let r = n % 8;
+            if r == 3 || r == 5 {
+                result = -result;
+            }
+        }
+
+        let tmp = a;
+        a = n;
+        n = tmp;
+
+        if a % 4 == 3 && n % 4 == 3 {
+            result = -result;
+        }
+        a %= n;
+    }
+
+    if n == 1 {
+        result
+    } else {
+        0
+    }
+}
+
+/// Computes the [Jacobi symbol](https://en.wikipedia.org/wiki/Jacobi_symbol) `(a/n)` for odd `n > 0`.
+///
+/// The Jacobi symbol agrees with the more general [`kronecker`] symbol for every odd `n > 0`.
+/// This function exists as a dedicated `u64`-modulus entry point for callers, such as a strong
+/// Lucas probable-primality test, that only ever need odd positive moduli and would otherwise
+/// have to cast `n` to `i64` before calling [`kronecker`], risking overflow for `n` close to
+/// [`u64::MAX`].
+///
+/// Returns `0` if `gcd(a, n) != 1`. `a` is reduced modulo `n` first, so negative `a` and
+/// `a >= n` are both handled by the same reciprocity loop.
+///
+/// `n == 0` is handled the same way as [`kronecker`]'s `n == 0` case, returning `1` if
+/// `a == 1 || a == -1` and `0` otherwise, instead of panicking on the division by zero that
+/// would otherwise follow from reducing `a` modulo `n`.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::jacobi;
+/// assert_eq!(jacobi(5, 21), 1);
+/// assert_eq!(jacobi(2, 9), 1);
+/// assert_eq!(jacobi(-1, 7), -1);
+/// assert_eq!(jacobi(3, 9), 0); // gcd(3, 9) = 3
+/// assert_eq!(jacobi(1, 0), 1);
+/// assert_eq!(jacobi(2, 0), 0);
+/// ```
+#[must_use]
+pub const fn jacobi(a: i64, n: u64) -> i8 {
+    if n == 0 {
+        return if a == 1 || a == -1 { 1 } else { 0 };
+    }
+
+    let mut result: i8 = 1;
+    let mut n = n;
+    let mut a = reduce_signed(a, n);
+
+    while a != 0 {
+        while a % 2 == 0 {
+            a /= 2;
+            let r = n % 8;
+            if r == 3 || r == 5 {
+                result = -result;
+            }
+        }
+
+        let tmp = a;
+        a = n;
+        n = tmp;
+
+        if a % 4 == 3 && n % 4 == 3 {
+            result = -result;
+        }
+        a %= n;
+    }
+
+    if n == 1 {
+        result
+    } else {
+        0
+    }
+}
+
+/// Reduces `x` into the range `[0, modulus)`.
+const fn reduce_signed(x: i64, modulus: u64) -> u64 {
+    let abs_mod = x.unsigned_abs() % modulus;
+    if x < 0 && abs_mod != 0 {
+        modulus - abs_mod
+    } else {
+        abs_mod
+    }
+}
+
+/// Calculates (`a` * `b`) mod `modulo` without overflow, routed through [`mod_mul_u128`] since
+/// [`mod_mul`](crate::integer_math::mod_mul) is unavailable when the `fast_test` feature is enabled.
+const fn mul_mod(a: u64, b: u64, modulo: u64) -> u64 {
+    mod_mul_u128(a as u128, b as u128, modulo as u128) as u64
+}
+
+/// Calculates (`a` - `b`) mod `modulo`, for `a, b < modulo`.
+const fn sub_mod(a: u64, b: u64, modulo: u64) -> u64 {
+    if a >= b {
+        a - b
+    } else {
+        modulo - (b - a)
+    }
+}
+
+/// Computes the `k`-th terms `(U_k, V_k)` of the [Lucas sequences](https://en.wikipedia.org/wiki/Lucas_sequence)
+/// with parameters `p` and `q`, reduced modulo `modulus`.
+///
+/// The sequences are defined by `U_0 = 0`, `U_1 = 1`, `V_0 = 2`, `V_1 = p`, and the shared
+/// recurrence `X_{n+1} = p * X_n - q * X_{n-1}`. With `p = 1, q = -1` this gives the Fibonacci
+/// and Lucas numbers, and in general it is the core primitive behind the strong Lucas primality
+/// test used in [BPSW](https://en.wikipedia.org/wiki/Baillie%E2%80%93PSW_primality_test).
+///
+/// Uses the doubling identities `U_{2n} = U_n * (2*U_{n+1} - p*U_n)` and
+/// `U_{2n+1} = U_{n+1}^2 - q*U_n^2`, so it only needs `O(log k)` multiplications modulo
+/// `modulus`, followed by a final `V_k = 2*U_{k+1} - p*U_k`.
+///
+/// Returns `(0, 0)` if `modulus == 0`, since no modular reduction is defined in that case. This
+/// mirrors how [`jacobi`] and [`kronecker`] treat their modulus/`n == 0` cases as a well-defined
+/// value instead of panicking, even though `lucas_u_v` has no [`Option`]/error type to signal the
+/// case through.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::lucas_u_v;
+/// // p = 1, q = -1 gives the Fibonacci and Lucas numbers.
+/// assert_eq!(lucas_u_v(1, -1, 10, 1_000_000_007), (55, 123)); // F_10 = 55, L_10 = 123
+/// assert_eq!(lucas_u_v(1, -1, 0, 7), (0, 2));
+/// assert_eq!(lucas_u_v(1, -1, 1, 7), (1, 1));
+/// assert_eq!(lucas_u_v(1, -1, 10, 0), (0, 0));
+/// ```
+#[must_use]
+pub const fn lucas_u_v(p: i64, q: i64, k: u64, modulus: u64) -> (u64, u64) {
+    if modulus == 0 {
+        return (0, 0);
+    }
+
+    let p_mod = reduce_signed(p, modulus);
+    let q_mod = reduce_signed(q, modulus);
+
+    // `(a, b)` holds `(U_n, U_{n+1})` for the `n` represented by the bits of `k` consumed so far.
+    let mut a: u64 = 0;
+    let mut b: u64 = 1;
+
+    let mut i = u64::BITS - k.leading_zeros();
+    while i > 0 {
+        i -= 1;
+
+        let u2n = mul_mod(
+            a,
+            sub_mod(mul_mod(2, b, modulus), mul_mod(p_mod, a, modulus), modulus),
+            modulus,
+        );
+        let u2n1 = sub_mod(
+            mul_mod(b, b, modulus),
+            mul_mod(q_mod, mul_mod(a, a, modulus), modulus),
+            modulus,
+        );
+        a = u2n;
+        b = u2n1;
+
+        if (k >> i) & 1 == 1 {
+            let next_b = sub_mod(
+                mul_mod(p_mod, b, modulus),
+                mul_mod(q_mod, a, modulus),
+                modulus,
+            );
+            a = b;
+            b = next_b;
+        }
+    }
+
+    let v = sub_mod(mul_mod(2, b, modulus), mul_mod(p_mod, a, modulus), modulus);
+    (a, v)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{discrete_log, jacobi, kronecker, lucas_u_v, multiplicative_order};
+
+    #[test]
+    fn check_multiplicative_order() {
+        assert_eq!(multiplicative_order(3, 7), Some(6));
+        assert_eq!(multiplicative_order(2, 7), Some(3));
+        assert_eq!(multiplicative_order(1, 7), Some(1));
+        assert_eq!(multiplicative_order(2, 4), None);
+        assert_eq!(multiplicative_order(5, 1), None);
+        assert_eq!(multiplicative_order(5, 0), None);
+        assert_eq!(multiplicative_order(10, 9), Some(1));
+    }
+
+    #[test]
+    fn check_discrete_log() {
+        assert_eq!(discrete_log::<3>(3, 5, 7), Some(5));
+        assert_eq!(discrete_log::<3>(2, 5, 7), None);
+        assert_eq!(discrete_log::<3>(2, 1, 7), Some(0));
+        assert_eq!(discrete_log::<4>(5, 3, 11), Some(2));
+        assert_eq!(discrete_log::<1>(2, 2, 2), None); // gcd(2, 2) != 1
+        assert_eq!(discrete_log::<3>(2, 3, 18), None); // gcd(2, 18) != 1
+    }
+
+    #[test]
+    fn check_kronecker() {
+        // Classic Jacobi symbol example: 9907 is prime, 1001 is not a square mod 9907.
+        assert_eq!(kronecker(1001, 9907), -1);
+
+        // `n` odd and positive reduces to the Jacobi symbol.
+        assert_eq!(kronecker(2, 9), 1);
+        assert_eq!(kronecker(3, 9), 0); // gcd(3, 9) != 1
+
+        // `(a/2)` depends only on `a mod 8`.
+        assert_eq!(kronecker(3, 8), -1); // (3/2)^3 = (-1)^3
+        assert_eq!(kronecker(7, 8), 1); // (7/2)^3 = 1^3
+        assert_eq!(kronecker(2, 8), 0); // a is even
+
+        // `n` negative folds in the sign of `a`.
+        assert_eq!(kronecker(5, -4), 1);
+        assert_eq!(kronecker(-1, -1), -1);
+        assert_eq!(kronecker(0, -1), 1);
+
+        // `n == 0`.
+        assert_eq!(kronecker(1, 0), 1);
+        assert_eq!(kronecker(-1, 0), 1);
+        assert_eq!(kronecker(2, 0), 0);
+
+        // `a == 0`.
+        assert_eq!(kronecker(0, 5), 0);
+        assert_eq!(kronecker(0, 1), 1);
+    }
+
+    #[test]
+    fn check_jacobi() {
+        assert_eq!(jacobi(5, 21), 1);
+        assert_eq!(jacobi(2, 9), 1);
+        assert_eq!(jacobi(-1, 7), -1);
+        assert_eq!(jacobi(3, 9), 0); // gcd(3, 9) = 3
+        assert_eq!(jacobi(1, 1), 1);
+
+        // Agrees with `kronecker` on odd, positive `n`.
+        for a in -20i64..20 {
+            for n in (1u64..50).step_by(2) {
+                assert_eq!(jacobi(a, n), kronecker(a, n as i64));
+            }
+        }
+    }
+
+    #[test]
+    fn check_lucas_u_v() {
+        // p = 1, q = -1 gives the Fibonacci and Lucas numbers.
+        assert_eq!(lucas_u_v(1, -1, 0, 1_000_000_007), (0, 2));
+        assert_eq!(lucas_u_v(1, -1, 1, 1_000_000_007), (1, 1));
+        assert_eq!(lucas_u_v(1, -1, 2, 1_000_000_007), (1, 3));
+        assert_eq!(lucas_u_v(1, -1, 5, 1_000_000_007), (5, 11));
+        assert_eq!(lucas_u_v(1, -1, 10, 1_000_000_007), (55, 123));
+
+        // A sequence with positive q.
+        assert_eq!(lucas_u_v(3, 2, 6, 1000), (63, 65));
+
+        // Reduction modulo a small modulus still matches the true values reduced afterwards.
+        assert_eq!(lucas_u_v(1, -1, 10, 7), (55 % 7, 123 % 7));
+
+        // `modulus == 0` has no well-defined reduction and must not panic.
+        assert_eq!(lucas_u_v(1, -1, 10, 0), (0, 0));
+        // `modulus == 1` reduces every term to 0.
+        assert_eq!(lucas_u_v(1, -1, 10, 1), (0, 0));
+    }
+}
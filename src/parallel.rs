@@ -0,0 +1,42 @@
+//! This module contains a `rayon`-parallelized bulk primality test, enabled by the `rayon` feature.
+
+extern crate std;
+
+use std::vec::Vec;
+
+use rayon::prelude::*;
+
+use crate::is_prime;
+
+/// Returns the primality of every number in `ns`, computed in parallel with `rayon`.
+///
+/// [`is_prime`] is pure, so testing the numbers in `ns` is embarrassingly parallel; this is a
+/// throughput win over testing them one at a time when `ns` is large. Requires the `rayon`
+/// feature, which pulls in the standard library.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// # use const_primes::are_prime_par;
+/// let ns = [2, 3, 4, 5, 6];
+/// assert_eq!(are_prime_par(&ns), vec![true, true, false, true, false]);
+/// ```
+#[must_use = "the function only returns a new value and does not modify its input"]
+pub fn are_prime_par(ns: &[u64]) -> Vec<bool> {
+    ns.par_iter().map(|&n| is_prime(n)).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{are_prime_par, Vec};
+    use crate::is_prime;
+
+    #[test]
+    fn check_are_prime_par() {
+        let ns: Vec<u64> = (0..1_000).collect();
+        let expected: Vec<bool> = ns.iter().map(|&n| is_prime(n)).collect();
+        assert_eq!(are_prime_par(&ns), expected);
+    }
+}
@@ -76,3 +76,95 @@ pub const fn previous_prime(n: u64) -> Option<u64> {
 pub const fn next_prime(n: u64) -> Option<u64> {
     bounded_search(n, 1)
 }
+
+/// A stateful walker over the primes greater than some starting point.
+///
+/// Remembers the last prime it yielded, so that generating a sequence of primes
+/// with repeated calls to [`next_prime`] doesn't require the caller to thread the
+/// previous result back in by hand.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// # use const_primes::PrimeWalker;
+/// let mut walker = PrimeWalker::new();
+/// assert_eq!(walker.next(), Some(2));
+/// assert_eq!(walker.next(), Some(3));
+/// assert_eq!(walker.next(), Some(5));
+/// ```
+///
+/// Starting from a given number instead of before the first prime:
+///
+/// ```
+/// # use const_primes::PrimeWalker;
+/// let mut walker = PrimeWalker::starting_from(10);
+/// assert_eq!(walker.next(), Some(11));
+/// assert_eq!(walker.next(), Some(13));
+/// assert_eq!(walker.next(), Some(17));
+/// ```
+///
+/// Since it's an [`Iterator`], it can be used with the usual iterator methods:
+///
+/// ```
+/// # use const_primes::PrimeWalker;
+/// let first_ten: Vec<u64> = PrimeWalker::new().take(10).collect();
+/// assert_eq!(first_ten, [2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+/// ```
+///
+/// It yields [`None`] once it passes the largest prime that fits in a `u64`:
+///
+/// ```
+/// # use const_primes::PrimeWalker;
+/// let mut walker = PrimeWalker::starting_from(18_446_744_073_709_551_557);
+/// assert_eq!(walker.next(), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct PrimeWalker {
+    current: u64,
+}
+
+impl PrimeWalker {
+    /// Creates a new walker that starts searching for primes before the smallest prime, `2`.
+    #[must_use = "the associated function only returns a new value"]
+    pub const fn new() -> Self {
+        Self { current: 0 }
+    }
+
+    /// Creates a new walker that will start searching for primes after `start`.
+    #[must_use = "the associated function only returns a new value"]
+    pub const fn starting_from(start: u64) -> Self {
+        Self { current: start }
+    }
+
+    /// Advances the walker to the next prime after its current position and returns it,
+    /// or returns [`None`] if there is no such prime that can be represented by a `u64`.
+    pub const fn next(&mut self) -> Option<u64> {
+        match next_prime(self.current) {
+            Some(prime) => {
+                self.current = prime;
+                Some(prime)
+            }
+            None => None,
+        }
+    }
+}
+
+impl Default for PrimeWalker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Iterator for PrimeWalker {
+    type Item = u64;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        PrimeWalker::next(self)
+    }
+}
+
+impl core::iter::FusedIterator for PrimeWalker {}
@@ -2,7 +2,7 @@
 
 use core::fmt;
 
-use crate::isqrt;
+use crate::{is_prime, isqrt, ArraySection};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) struct SegmentedSieveError;
@@ -15,6 +15,23 @@ impl fmt::Display for SegmentedSieveError {
 
 impl core::error::Error for SegmentedSieveError {}
 
+/// Computes `upper_limit - N`, the lower end of the segment that [`sieve_segment`] sieves.
+///
+/// This is the single guarded subtraction used by [`sieve_segment`], so that every caller that
+/// needs the segment bounds goes through the same overflow check instead of repeating
+/// `upper_limit - N` (which would panic in debug builds if `upper_limit < N`).
+///
+/// # Errors
+///
+/// Returns an error if `upper_limit` < `N`.
+#[must_use = "the function only returns a new value and does not modify its inputs"]
+const fn segment_lower_limit<const N: usize>(upper_limit: u64) -> Result<u64, SegmentedSieveError> {
+    match upper_limit.checked_sub(N as u64) {
+        Some(diff) => Ok(diff),
+        None => Err(SegmentedSieveError),
+    }
+}
+
 /// Uses the primalities of the first `N` integers in `base_sieve` to sieve the numbers in the range `[upper_limit - N, upper_limit)`.
 /// Assumes that the base sieve contains the prime status of the `N` fist integers. The output is only meaningful
 /// for the numbers below `N^2`.
@@ -29,9 +46,9 @@ pub(crate) const fn sieve_segment<const N: usize>(
 ) -> Result<[bool; N], SegmentedSieveError> {
     let mut segment_sieve = [true; N];
 
-    let lower_limit = match upper_limit.checked_sub(N as u64) {
-        Some(diff) => diff,
-        None => return Err(SegmentedSieveError),
+    let lower_limit = match segment_lower_limit::<N>(upper_limit) {
+        Ok(diff) => diff,
+        Err(e) => return Err(e),
     };
 
     if lower_limit == 0 && N > 1 {
@@ -247,9 +264,78 @@ pub const fn sieve<const N: usize>() -> [bool; N] {
     sieve
 }
 
+/// Returns an array of size `N` where the value at a given index indicates whether the index is prime,
+/// the same as [`sieve`], but marks the multiples of 2 and 3 up front instead of discovering 2 and 3
+/// as primes and crossing off their multiples in the main loop.
+///
+/// Only candidates of the form `6k ± 1` are ever tested for primality, which roughly a third of the
+/// work [`sieve`] does, since it only needs to look at every third remaining number instead of every
+/// number. The output is bit-identical to [`sieve`] for the same `N`.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::sieve_wheel;
+/// const PRIMALITY: [bool; 10] = sieve_wheel();
+/// //                     0      1      2     3     4      5     6      7     8      9
+/// assert_eq!(PRIMALITY, [false, false, true, true, false, true, false, true, false, false]);
+/// ```
+#[must_use = "the function only returns a new value"]
+pub const fn sieve_wheel<const N: usize>() -> [bool; N] {
+    let mut sieve = [true; N];
+    if N > 0 {
+        sieve[0] = false;
+    }
+    if N > 1 {
+        sieve[1] = false;
+    }
+
+    // Mark the multiples of 2, keeping 2 itself prime.
+    let mut composite = 4;
+    while composite < N {
+        sieve[composite] = false;
+        composite += 2;
+    }
+
+    // Mark the multiples of 3 that aren't already marked, keeping 3 itself prime.
+    composite = 9;
+    while composite < N {
+        sieve[composite] = false;
+        composite += 6;
+    }
+
+    // From here on only the numbers of the form `6k + 1` and `6k + 5` can be prime,
+    // so we only need to check those, alternating between the gaps of 2 and 4 that
+    // separate consecutive members of that sequence: 5, 7, 11, 13, 17, 19, ...
+    let mut number: usize = 5;
+    let mut gap: usize = 2;
+    let bound = isqrt(N as u64);
+    while (number as u64) <= bound {
+        if sieve[number] {
+            // If a number is prime we enumerate the odd multiples of it starting from its square,
+            // since the even multiples were already marked above.
+            let Some(mut composite) = number.checked_mul(number) else {
+                break;
+            };
+
+            while composite < N {
+                sieve[composite] = false;
+                composite = match composite.checked_add(2 * number) {
+                    Some(sum) => sum,
+                    None => break,
+                };
+            }
+        }
+        number += gap;
+        gap = 6 - gap;
+    }
+
+    sieve
+}
+
 /// The error returned by [`sieve_lt`] and [`sieve_geq`] if the input
 /// is invalid or does not work to sieve the requested range.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(
     feature = "rkyv",
@@ -319,6 +405,18 @@ impl core::error::Error for SieveError {}
 /// assert_eq!(BIG_PRIME_STATUS, Ok([false,         true,          false]));
 /// ```
 ///
+/// `MEM` only needs to cover the *requested* window `[lower_limit, lower_limit + N)`, not the
+/// full `MEM`-sized sieve buffer used internally, so `N` smaller than `MEM` can reach slightly
+/// past `MEM`^2 in the unused tail of the buffer without erroring:
+///
+/// ```
+/// # use const_primes::sieve_geq;
+/// // `MEM`^2 is 16, so the buffer would cover up to 17, but only 13, 14 and 15 are requested.
+/// const PRIME_STATUS: [bool; 3] = match sieve_geq::<3, 4>(13) {Ok(s) => s, Err(_) => panic!()};
+/// //                        13,    14,    15
+/// assert_eq!(PRIME_STATUS, [true,  false, false]);
+/// ```
+///
 /// # Errors
 ///
 /// Returns an error if `MEM + lower_limit` is larger than `MEM^2` or doesn't fit in a `u64`:
@@ -360,14 +458,18 @@ pub const fn sieve_geq<const N: usize, const MEM: usize>(
         return Err(SieveError::TotalDoesntFitU64);
     };
 
-    if upper_limit > mem_sqr {
-        return Err(SieveError::TooSmallSieveSize);
-    }
-
     if N == 0 {
         return Ok([false; N]);
     }
 
+    // Only the requested window `[lower_limit, lower_limit + N)` needs to be decidable: the rest
+    // of the `MEM`-sized sieve buffer may run past `MEM`^2 without making the request invalid,
+    // since those extra slots are never read out of `upper_sieve` below.
+    let requested_upper_limit = lower_limit + N as u64;
+    if requested_upper_limit > mem_sqr {
+        return Err(SieveError::TooSmallSieveSize);
+    }
+
     // If `lower_limit` is zero then this is the same as just calling `sieve`, and we can return early.
     if lower_limit == 0 {
         // We do not merge it with the computation of `base_sieve` below, since here we only
@@ -391,6 +493,652 @@ pub const fn sieve_geq<const N: usize, const MEM: usize>(
     Ok(ans)
 }
 
+/// Returns whether `n` is prime, using a precomputed `base_sieve` of size `MEM` (e.g. one
+/// produced by [`sieve`]) instead of computing one from scratch.
+///
+/// Works for any `n < MEM`^2. If `n < MEM`, the answer is read directly out of `base_sieve`;
+/// otherwise a single segment is sieved from it with [`sieve_segment`], the same way [`sieve_geq`]
+/// does internally.
+///
+/// This is useful when checking the primality of many numbers in the same large range:
+/// [`sieve_geq`] recomputes `base_sieve` on every call, which is wasteful if it's already
+/// available.
+///
+/// # Errors
+///
+/// Returns an error if `n` is larger than or equal to `MEM`^2.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::{is_prime_via_sieve, sieve, SieveError};
+/// const BASE_SIEVE: [bool; 11] = sieve();
+/// assert_eq!(is_prime_via_sieve(&BASE_SIEVE, 97), Ok(true));
+/// assert_eq!(is_prime_via_sieve(&BASE_SIEVE, 100), Ok(false));
+/// assert_eq!(
+///     is_prime_via_sieve(&BASE_SIEVE, 121),
+///     Err(SieveError::TooSmallSieveSize)
+/// );
+/// ```
+#[must_use = "the function only returns a new value and does not modify its inputs"]
+pub const fn is_prime_via_sieve<const MEM: usize>(
+    base_sieve: &[bool; MEM],
+    n: u64,
+) -> Result<bool, SieveError> {
+    let (mem64, mem_sqr) = const {
+        let mem64 = MEM as u64;
+        match mem64.checked_mul(mem64) {
+            Some(mem_sqr) => (mem64, mem_sqr),
+            None => panic!("`MEM`^2 must fit in a `u64`"),
+        }
+    };
+
+    if n >= mem_sqr {
+        return Err(SieveError::TooSmallSieveSize);
+    }
+
+    if n < mem64 {
+        return Ok(base_sieve[n as usize]);
+    }
+
+    let segment = match sieve_segment(base_sieve, n + 1) {
+        Ok(s) => s,
+        Err(_) => panic!("`n + 1` is greater than `MEM` here, since `n >= MEM`"),
+    };
+
+    Ok(segment[MEM - 1])
+}
+
+/// Returns the first pair of consecutive primes `(p, q)` such that `q - p >= g`.
+///
+/// Searches by sieving successive segments of size `MEM` with [`sieve_segment`],
+/// starting from 2, and keeping track of the previously found prime.
+///
+/// # Errors
+///
+/// Returns an error if no such pair is found among the numbers smaller than `MEM`^2.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::first_gap_geq;
+/// // The first prime gap of at least 6 is the one between 23 and 29.
+/// const GAP: Result<(u64, u64), const_primes::SieveError> = first_gap_geq::<30>(6);
+/// assert_eq!(GAP, Ok((23, 29)));
+/// ```
+#[must_use = "the function only returns a new value"]
+pub const fn first_gap_geq<const MEM: usize>(g: u32) -> Result<(u64, u64), SieveError> {
+    let g = g as u64;
+
+    let mem_sqr = const {
+        let mem64 = MEM as u64;
+        match mem64.checked_mul(mem64) {
+            Some(mem_sqr) => mem_sqr,
+            None => panic!("`MEM`^2 must fit in a `u64`"),
+        }
+    };
+
+    let base_sieve: [bool; MEM] = sieve();
+
+    let mut previous_prime: Option<u64> = None;
+    let mut i = 0;
+    while i < MEM {
+        if base_sieve[i] {
+            let prime = i as u64;
+            if let Some(prev) = previous_prime {
+                if prime - prev >= g {
+                    return Ok((prev, prime));
+                }
+            }
+            previous_prime = Some(prime);
+        }
+        i += 1;
+    }
+
+    let mut upper_limit = MEM as u64;
+    while upper_limit < mem_sqr {
+        let Some(next_upper_limit) = upper_limit.checked_add(MEM as u64) else {
+            return Err(SieveError::TotalDoesntFitU64);
+        };
+
+        let segment = match sieve_segment(&base_sieve, next_upper_limit) {
+            Ok(s) => s,
+            Err(_) => return Err(SieveError::TooSmallSieveSize),
+        };
+
+        let mut j = 0;
+        while j < MEM {
+            if segment[j] {
+                let prime = upper_limit + j as u64;
+                if let Some(prev) = previous_prime {
+                    if prime - prev >= g {
+                        return Ok((prev, prime));
+                    }
+                }
+                previous_prime = Some(prime);
+            }
+            j += 1;
+        }
+        upper_limit = next_upper_limit;
+    }
+
+    Err(SieveError::TooSmallSieveSize)
+}
+
+/// Returns the `n`-th (0-indexed) pair of twin primes `(p, p + 2)`.
+///
+/// Searches by sieving successive segments of size `MEM` with [`sieve_segment`],
+/// starting from 2, without ever storing more than one segment's worth of twin pairs at a time.
+///
+/// # Errors
+///
+/// Returns an error if fewer than `n + 1` twin prime pairs are found among the numbers smaller than `MEM`^2.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::nth_twin_prime;
+/// const FIRST: Result<(u64, u64), const_primes::SieveError> = nth_twin_prime::<30>(0);
+/// const THIRD: Result<(u64, u64), const_primes::SieveError> = nth_twin_prime::<30>(2);
+/// assert_eq!(FIRST, Ok((3, 5)));
+/// assert_eq!(THIRD, Ok((11, 13)));
+/// ```
+#[must_use = "the function only returns a new value"]
+pub const fn nth_twin_prime<const MEM: usize>(n: usize) -> Result<(u64, u64), SieveError> {
+    let mem_sqr = const {
+        let mem64 = MEM as u64;
+        match mem64.checked_mul(mem64) {
+            Some(mem_sqr) => mem_sqr,
+            None => panic!("`MEM`^2 must fit in a `u64`"),
+        }
+    };
+
+    let base_sieve: [bool; MEM] = sieve();
+
+    let mut previous_prime: Option<u64> = None;
+    let mut count = 0;
+    let mut i = 0;
+    while i < MEM {
+        if base_sieve[i] {
+            let prime = i as u64;
+            if let Some(prev) = previous_prime {
+                if prime - prev == 2 {
+                    if count == n {
+                        return Ok((prev, prime));
+                    }
+                    count += 1;
+                }
+            }
+            previous_prime = Some(prime);
+        }
+        i += 1;
+    }
+
+    let mut upper_limit = MEM as u64;
+    while upper_limit < mem_sqr {
+        let Some(next_upper_limit) = upper_limit.checked_add(MEM as u64) else {
+            return Err(SieveError::TotalDoesntFitU64);
+        };
+
+        let segment = match sieve_segment(&base_sieve, next_upper_limit) {
+            Ok(s) => s,
+            Err(_) => return Err(SieveError::TooSmallSieveSize),
+        };
+
+        let mut j = 0;
+        while j < MEM {
+            if segment[j] {
+                let prime = upper_limit + j as u64;
+                if let Some(prev) = previous_prime {
+                    if prime - prev == 2 {
+                        if count == n {
+                            return Ok((prev, prime));
+                        }
+                        count += 1;
+                    }
+                }
+                previous_prime = Some(prime);
+            }
+            j += 1;
+        }
+        upper_limit = next_upper_limit;
+    }
+
+    Err(SieveError::TooSmallSieveSize)
+}
+
+/// Returns the first prime that begins a run of `run_length` consecutive primes that all end
+/// in the decimal digit `digit`.
+///
+/// Searches by sieving successive segments of size `MEM` with [`sieve_segment`], starting from 2,
+/// tracking the length of the current run of primes ending in `digit` as it goes. Related to the
+/// last-digit bias tracked by [`last_digit_distribution`](crate::stats::last_digit_distribution).
+///
+/// Returns the first prime of the first such run if `run_length == 0`, since a run of zero primes
+/// is vacuously present there.
+///
+/// # Errors
+///
+/// Returns an error if no such run is found among the numbers smaller than `MEM`^2. In particular
+/// this is always the case if `digit > 9`, since no prime ends in such a digit.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::find_last_digit_run;
+/// // 283 and 293 are the first two consecutive primes that both end in 3.
+/// const RUN: Result<u64, const_primes::SieveError> = find_last_digit_run::<300>(3, 2);
+/// assert_eq!(RUN, Ok(283));
+/// ```
+#[must_use = "the function only returns a new value"]
+pub const fn find_last_digit_run<const MEM: usize>(
+    digit: u8,
+    run_length: usize,
+) -> Result<u64, SieveError> {
+    let digit = digit as u64;
+
+    if run_length == 0 {
+        return Ok(2);
+    }
+
+    let mem_sqr = const {
+        let mem64 = MEM as u64;
+        match mem64.checked_mul(mem64) {
+            Some(mem_sqr) => mem_sqr,
+            None => panic!("`MEM`^2 must fit in a `u64`"),
+        }
+    };
+
+    let base_sieve: [bool; MEM] = sieve();
+
+    let mut run_start: Option<u64> = None;
+    let mut run_count: usize = 0;
+    let mut i = 0;
+    while i < MEM {
+        if base_sieve[i] {
+            let prime = i as u64;
+            if prime % 10 == digit {
+                if run_count == 0 {
+                    run_start = Some(prime);
+                }
+                run_count += 1;
+                if run_count == run_length {
+                    return Ok(match run_start {
+                        Some(p) => p,
+                        None => unreachable!(),
+                    });
+                }
+            } else {
+                run_count = 0;
+                run_start = None;
+            }
+        }
+        i += 1;
+    }
+
+    let mut upper_limit = MEM as u64;
+    while upper_limit < mem_sqr {
+        let Some(next_upper_limit) = upper_limit.checked_add(MEM as u64) else {
+            return Err(SieveError::TotalDoesntFitU64);
+        };
+
+        let segment = match sieve_segment(&base_sieve, next_upper_limit) {
+            Ok(s) => s,
+            Err(_) => return Err(SieveError::TooSmallSieveSize),
+        };
+
+        let mut j = 0;
+        while j < MEM {
+            if segment[j] {
+                let prime = upper_limit + j as u64;
+                if prime % 10 == digit {
+                    if run_count == 0 {
+                        run_start = Some(prime);
+                    }
+                    run_count += 1;
+                    if run_count == run_length {
+                        return Ok(match run_start {
+                            Some(p) => p,
+                            None => unreachable!(),
+                        });
+                    }
+                } else {
+                    run_count = 0;
+                    run_start = None;
+                }
+            }
+            j += 1;
+        }
+        upper_limit = next_upper_limit;
+    }
+
+    Err(SieveError::TooSmallSieveSize)
+}
+
+/// Returns the prime immediately below `n` and the prime immediately above `n`, as
+/// `(previous_prime, next_prime)`.
+///
+/// Searches by sieving successive segments of size `MEM` with [`sieve_segment`], starting from 2,
+/// which finds both neighbors in a single sweep, rather than scanning outward from `n` in both
+/// directions with [`previous_prime`](crate::previous_prime) and [`next_prime`](crate::next_prime)
+/// separately.
+///
+/// The previous prime is [`None`] if `n` is smaller than or equal to 2, since there is no prime
+/// smaller than 2.
+///
+/// # Errors
+///
+/// Returns an error if the next prime after `n` is not smaller than `MEM`^2, since primality above
+/// that point can not be determined by this sieve.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::nearest_primes;
+/// const NEIGHBORS: Result<(Option<u64>, Option<u64>), const_primes::SieveError> =
+///     nearest_primes::<30>(20);
+/// assert_eq!(NEIGHBORS, Ok((Some(19), Some(23))));
+///
+/// const NONE_BELOW: Result<(Option<u64>, Option<u64>), const_primes::SieveError> =
+///     nearest_primes::<30>(2);
+/// assert_eq!(NONE_BELOW, Ok((None, Some(3))));
+/// ```
+#[must_use = "the function only returns a new value"]
+pub const fn nearest_primes<const MEM: usize>(
+    n: u64,
+) -> Result<(Option<u64>, Option<u64>), SieveError> {
+    let mem_sqr = const {
+        let mem64 = MEM as u64;
+        match mem64.checked_mul(mem64) {
+            Some(mem_sqr) => mem_sqr,
+            None => panic!("`MEM`^2 must fit in a `u64`"),
+        }
+    };
+
+    let base_sieve: [bool; MEM] = sieve();
+
+    let mut previous: Option<u64> = None;
+    let mut next: Option<u64> = None;
+
+    let mut i = 0;
+    while i < MEM {
+        if base_sieve[i] {
+            let prime = i as u64;
+            if prime < n {
+                previous = Some(prime);
+            } else if prime > n {
+                next = Some(prime);
+                break;
+            }
+        }
+        i += 1;
+    }
+
+    let mut upper_limit = MEM as u64;
+    while next.is_none() && upper_limit < mem_sqr {
+        let Some(next_upper_limit) = upper_limit.checked_add(MEM as u64) else {
+            return Err(SieveError::TotalDoesntFitU64);
+        };
+
+        let segment = match sieve_segment(&base_sieve, next_upper_limit) {
+            Ok(s) => s,
+            Err(_) => return Err(SieveError::TooSmallSieveSize),
+        };
+
+        let mut j = 0;
+        while j < MEM {
+            if segment[j] {
+                let prime = upper_limit + j as u64;
+                if prime < n {
+                    previous = Some(prime);
+                } else if prime > n {
+                    next = Some(prime);
+                    break;
+                }
+            }
+            j += 1;
+        }
+        upper_limit = next_upper_limit;
+    }
+
+    if next.is_none() {
+        return Err(SieveError::TooSmallSieveSize);
+    }
+
+    Ok((previous, next))
+}
+
+/// Returns the first `N` constellations `[base + pattern[0], ..., base + pattern[K - 1]]`, taken
+/// at the smallest starting points `base` such that `base + pattern[i]` is prime for every
+/// offset in `pattern`.
+///
+/// Generalises [`nth_twin_prime`], which looks for the fixed pattern `[0, 2]`; passing
+/// `[0, 2, 6]` instead finds prime triplets of the form `(p, p + 2, p + 6)`.
+///
+/// Searches by sieving successive segments of size `MEM` with [`sieve_segment`], like
+/// [`nth_twin_prime`] and [`nearest_primes`] do, checking each candidate's offsets against the
+/// segment holding it (and, for offsets that spill past its end, the next one). [`is_prime`] is
+/// only used as a fallback for the rare offset larger than `MEM` itself. Stops after finding `N`
+/// starting points or after `base` reaches `MEM`^2, whichever comes first, so if fewer than `N`
+/// are found the returned [`ArraySection`] will contain fewer than `N` elements.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::prime_constellations;
+/// // Find the first two prime triplets of the form (p, p + 2, p + 6).
+/// let triplets = prime_constellations::<2, 3, 30>([0, 2, 6]);
+/// assert_eq!(triplets.as_slice(), &[[5, 7, 11], [11, 13, 17]]);
+/// ```
+#[must_use = "the function only returns a new value"]
+pub const fn prime_constellations<const N: usize, const K: usize, const MEM: usize>(
+    pattern: [u64; K],
+) -> ArraySection<[u64; K], N> {
+    let mem_sqr = const {
+        let mem64 = MEM as u64;
+        match mem64.checked_mul(mem64) {
+            Some(mem_sqr) => mem_sqr,
+            None => panic!("`MEM`^2 must fit in a `u64`"),
+        }
+    };
+
+    let mut result = [[0u64; K]; N];
+    let mut count = 0;
+
+    if N == 0 || K == 0 || MEM == 0 {
+        return ArraySection::new(result, count);
+    }
+
+    let base_sieve: [bool; MEM] = sieve();
+
+    // `lookahead` holds the primality of the segment right after `segment`, so a candidate near
+    // the end of `segment` can still have `base + pattern[i]` checked against a sieved bit
+    // instead of falling back to `is_prime`.
+    let mut segment_start = 0u64;
+    let mut segment = base_sieve;
+    let mut lookahead_start = MEM as u64;
+    let mut lookahead: [bool; MEM] = if lookahead_start < mem_sqr {
+        match sieve_segment(&base_sieve, lookahead_start + MEM as u64) {
+            Ok(s) => s,
+            Err(_) => [false; MEM],
+        }
+    } else {
+        [false; MEM]
+    };
+
+    while segment_start < mem_sqr && count < N {
+        let mut j = 0;
+        while j < MEM && count < N {
+            let base = segment_start + j as u64;
+            if base >= mem_sqr {
+                break;
+            }
+
+            let mut constellation_found = true;
+            let mut i = 0;
+            while i < K {
+                let Some(candidate) = base.checked_add(pattern[i]) else {
+                    constellation_found = false;
+                    break;
+                };
+                let Some(offset_from_segment) = candidate.checked_sub(segment_start) else {
+                    constellation_found = false;
+                    break;
+                };
+
+                let is_candidate_prime = if (offset_from_segment as usize) < MEM {
+                    segment[offset_from_segment as usize]
+                } else if (offset_from_segment as usize) < 2 * MEM {
+                    lookahead[offset_from_segment as usize - MEM]
+                } else {
+                    // The pattern reaches further than one lookahead segment; this should be
+                    // rare in practice (it requires an offset larger than `MEM`), so fall back
+                    // to a direct primality test rather than fetching more segments.
+                    is_prime(candidate)
+                };
+                if !is_candidate_prime {
+                    constellation_found = false;
+                    break;
+                }
+                i += 1;
+            }
+
+            if constellation_found {
+                let mut constellation = [0u64; K];
+                let mut m = 0;
+                while m < K {
+                    // The checks above already proved every one of these additions doesn't overflow.
+                    constellation[m] = base + pattern[m];
+                    m += 1;
+                }
+                result[count] = constellation;
+                count += 1;
+            }
+
+            j += 1;
+        }
+
+        segment_start += MEM as u64;
+        segment = lookahead;
+        lookahead_start += MEM as u64;
+        lookahead = if lookahead_start < mem_sqr {
+            match sieve_segment(&base_sieve, lookahead_start + MEM as u64) {
+                Ok(s) => s,
+                Err(_) => [false; MEM],
+            }
+        } else {
+            [false; MEM]
+        };
+    }
+
+    ArraySection::new(result, count)
+}
+
+/// Returns an `S`×`S` grid where cell `(x, y)` indicates whether the integer at that position
+/// in an [Ulam spiral](https://en.wikipedia.org/wiki/Ulam_spiral) is prime.
+///
+/// The spiral starts at `0` in the cell `(S / 2, S / 2)` and winds outward
+/// counter-clockwise (right, up, left, down, with the leg length growing by one every other
+/// turn), filling in consecutive integers until the grid is full or the spiral would step
+/// outside of it.
+///
+/// Since `SS` can not be computed from `S` alone on stable Rust, it must be supplied explicitly
+/// and is checked to equal `S * S`. The [`ulam_spiral!`] macro hides this requirement.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::ulam_spiral;
+/// const SPIRAL: [[bool; 3]; 3] = ulam_spiral::<3, 9>();
+/// // The spiral visits the grid in this order:
+/// // 4 3 2
+/// // 5 0 1
+/// // 6 7 8
+/// assert_eq!(
+///     SPIRAL,
+///     [
+///         [false, true, true],
+///         [true, false, false],
+///         [false, true, false],
+///     ]
+/// );
+/// ```
+///
+/// # Panics
+///
+/// Panics at compile time if `SS` is not equal to `S * S`.
+#[must_use = "the function only returns a new value"]
+pub const fn ulam_spiral<const S: usize, const SS: usize>() -> [[bool; S]; S] {
+    const {
+        assert!(SS == S * S, "`SS` must equal `S` * `S`");
+    }
+
+    let mut grid = [[false; S]; S];
+
+    if S == 0 {
+        return grid;
+    }
+
+    let primality: [bool; SS] = sieve();
+
+    let mut x = S / 2;
+    let mut y = S / 2;
+    let mut number: usize = 0;
+    grid[y][x] = primality[0];
+
+    // Right, up, left, down.
+    const DX: [isize; 4] = [1, 0, -1, 0];
+    const DY: [isize; 4] = [0, -1, 0, 1];
+
+    let mut direction = 0;
+    let mut steps_in_leg = 1;
+    let mut legs_done = 0;
+
+    while number + 1 < SS {
+        let mut step = 0;
+        while step < steps_in_leg && number + 1 < SS {
+            let nx = x as isize + DX[direction];
+            let ny = y as isize + DY[direction];
+            if nx < 0 || ny < 0 || nx as usize >= S || ny as usize >= S {
+                // The spiral has walked off the grid; there is nothing more to place.
+                return grid;
+            }
+            x = nx as usize;
+            y = ny as usize;
+            number += 1;
+            grid[y][x] = primality[number];
+            step += 1;
+        }
+        direction = (direction + 1) % 4;
+        legs_done += 1;
+        if legs_done == 2 {
+            legs_done = 0;
+            steps_in_leg += 1;
+        }
+    }
+
+    grid
+}
+
+/// Expands to a `const [[bool; S]; S]` holding an [Ulam spiral](https://en.wikipedia.org/wiki/Ulam_spiral) of primality.
+///
+/// Hides the `S * S` arithmetic that [`ulam_spiral`] would otherwise require the caller to
+/// spell out by hand, since it can not be computed from `S` alone on stable Rust.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::ulam_spiral;
+/// const SPIRAL: [[bool; 3]; 3] = ulam_spiral!(3);
+/// assert_eq!(SPIRAL, ulam_spiral::<3, 9>());
+/// ```
+#[macro_export]
+macro_rules! ulam_spiral {
+    ($s:expr) => {
+        $crate::ulam_spiral::<{ $s }, { $s * $s }>()
+    };
+}
+
 /// Generate arrays of the prime status of large numbers without having to store the prime status
 /// of every single integer smaller than the target in the result, and thus potentially the binary.
 ///
@@ -402,6 +1150,8 @@ pub const fn sieve_geq<const N: usize, const MEM: usize>(
 /// Computes the sieve size as `isqrt(upper_limit) + 1` for [`sieve_lt`]
 /// and as `isqrt(lower_limit) + 1 + N` for [`sieve_geq`].
 /// This may overestimate the memory requirement for `sieve_geq`.
+/// The exact value it chooses can be inspected ahead of time through
+/// [`chosen_mem_lt`](crate::chosen_mem_lt) and [`chosen_mem_geq`](crate::chosen_mem_geq).
 ///
 /// # Examples
 ///
@@ -428,30 +1178,53 @@ pub const fn sieve_geq<const N: usize, const MEM: usize>(
 #[macro_export]
 macro_rules! sieve_segment {
     ($n:expr; < $lim:expr) => {
-        $crate::sieve_lt::<
-            { $n },
-            {
-                let mem: u64 = { $lim };
-                $crate::isqrt(mem) as ::core::primitive::usize + 1
-            },
-        >({ $lim })
+        $crate::sieve_lt::<{ $n }, { $crate::chosen_mem_lt({ $lim }) }>({ $lim })
     };
     ($n:expr; >= $lim:expr) => {
-        $crate::sieve_geq::<
-            { $n },
-            {
-                let mem: u64 = { $lim };
-                $crate::isqrt(mem) as ::core::primitive::usize + 1 + { $n }
-            },
-        >({ $lim })
+        $crate::sieve_geq::<{ $n }, { $crate::chosen_mem_geq({ $lim }, { $n }) }>({ $lim })
     };
 }
 
+/// Expands to a `const [bool; HIGH - LOW]` holding the prime status of every integer in `LOW..HIGH`.
+///
+/// Hides the anchor/count/`MEM` arithmetic that [`sieve_geq`] would otherwise require the caller
+/// to work out by hand, and panics at compile time if the derived `MEM` ever turns out to be too
+/// small. This makes the expansion a plain array, so it's usable directly as a `const` or `static`.
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::prime_table;
+/// const TABLE: [bool; 5] = prime_table!(10..15);
+/// //                 10     11    12     13    14
+/// assert_eq!(TABLE, [false, true, false, true, false]);
+///
+/// static TABLE_STATIC: [bool; 5] = prime_table!(10..15);
+/// assert_eq!(TABLE_STATIC, TABLE);
+/// ```
+#[macro_export]
+macro_rules! prime_table {
+    ($range:expr) => {{
+        const RANGE: ::core::ops::Range<u64> = $range;
+        const LEN: usize = (RANGE.end - RANGE.start) as usize;
+        const MEM: usize = $crate::isqrt(RANGE.start) as usize + 1 + LEN;
+        const TABLE: [bool; LEN] = match $crate::sieve_geq::<LEN, MEM>(RANGE.start) {
+            Ok(table) => table,
+            Err(_) => panic!("the automatically derived sieve size was too small for this range"),
+        };
+        TABLE
+    }};
+}
+
 #[cfg(test)]
 mod test {
     use crate::SieveError;
 
-    use super::{sieve, sieve_geq, sieve_lt, sieve_segment, SegmentedSieveError};
+    use super::{
+        find_last_digit_run, first_gap_geq, is_prime_via_sieve, nearest_primes, nth_twin_prime,
+        prime_constellations, sieve, sieve_geq, sieve_lt, sieve_segment, sieve_wheel, ulam_spiral,
+        SegmentedSieveError,
+    };
 
     #[test]
     fn test_consistency_of_sieve_segment() {
@@ -470,6 +1243,75 @@ mod test {
             Err(SegmentedSieveError)
         );
         assert_eq!(sieve_segment(&sieve::<5>(), 5), Ok(sieve()));
+        // `upper_limit < N` must be rejected instead of panicking on the underflowing subtraction.
+        assert_eq!(
+            sieve_segment::<5>(&[false, false, true, true, false], 0),
+            Err(SegmentedSieveError)
+        );
+    }
+
+    #[test]
+    fn test_prime_constellations() {
+        // Twin primes found through a pattern matching `nth_twin_prime`.
+        let twins = prime_constellations::<3, 2, 30>([0, 2]);
+        assert_eq!(twins.as_slice(), &[[3, 5], [5, 7], [11, 13]]);
+
+        // Prime triplets of the form (p, p + 2, p + 6).
+        let triplets = prime_constellations::<2, 3, 30>([0, 2, 6]);
+        assert_eq!(triplets.as_slice(), &[[5, 7, 11], [11, 13, 17]]);
+
+        // There are fewer than 1000 such triplets among the numbers smaller than 30^2.
+        let too_many = prime_constellations::<1_000, 3, 30>([0, 2, 6]);
+        assert!(too_many.len() < 1_000);
+
+        // A `MEM` smaller than the pattern's own span forces candidates to be checked against
+        // the lookahead segment (and, past that, the `is_prime` fallback), which must still
+        // agree with the larger-`MEM` result above.
+        let small_mem_triplets = prime_constellations::<2, 3, 4>([0, 2, 6]);
+        assert_eq!(small_mem_triplets.as_slice(), triplets.as_slice());
+    }
+
+    #[test]
+    fn test_ulam_spiral() {
+        assert_eq!(ulam_spiral::<0, 0>(), [[false; 0]; 0]);
+        assert_eq!(ulam_spiral::<1, 1>(), [[false]]);
+        assert_eq!(
+            ulam_spiral::<3, 9>(),
+            [
+                [false, true, true],
+                [true, false, false],
+                [false, true, false],
+            ]
+        );
+        assert_eq!(ulam_spiral::<3, 9>(), crate::ulam_spiral!(3));
+    }
+
+    #[test]
+    fn test_is_prime_via_sieve() {
+        const BASE_SIEVE: [bool; 11] = sieve();
+        for n in 0..121 {
+            assert_eq!(
+                is_prime_via_sieve(&BASE_SIEVE, n),
+                Ok(crate::is_prime(n)),
+                "failed for n = {n}"
+            );
+        }
+        assert_eq!(
+            is_prime_via_sieve(&BASE_SIEVE, 121),
+            Err(SieveError::TooSmallSieveSize)
+        );
+    }
+
+    #[test]
+    fn test_prime_table_macro() {
+        const TABLE: [bool; 5] = crate::prime_table!(10..15);
+        assert_eq!(TABLE, [false, true, false, true, false]);
+
+        static TABLE_STATIC: [bool; 5] = crate::prime_table!(10..15);
+        assert_eq!(TABLE_STATIC, TABLE);
+
+        const EMPTY: [bool; 0] = crate::prime_table!(10..10);
+        assert_eq!(EMPTY, [] as [bool; 0]);
     }
 
     #[test]
@@ -485,6 +1327,82 @@ mod test {
         assert_eq!(sieve(), [false; 0]);
     }
 
+    #[test]
+    fn test_sieve_wheel() {
+        macro_rules! check {
+            ($($n:literal),+ $(,)?) => {
+                $(assert_eq!(sieve_wheel::<$n>(), sieve::<$n>(), "mismatch for N = {}", $n);)+
+            };
+        }
+        check!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 16, 25, 30, 100, 121, 1_000, 10_000, 100_000);
+    }
+
+    #[test]
+    fn test_sieve_error_is_ordered() {
+        let mut errors = [
+            SieveError::TotalDoesntFitU64,
+            SieveError::TooSmallLimit,
+            SieveError::TooSmallSieveSize,
+        ];
+        errors.sort();
+        assert_eq!(
+            errors,
+            [
+                SieveError::TooSmallLimit,
+                SieveError::TooSmallSieveSize,
+                SieveError::TotalDoesntFitU64,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_first_gap_geq() {
+        assert_eq!(first_gap_geq::<30>(6), Ok((23, 29)));
+        assert_eq!(first_gap_geq::<10>(1), Ok((2, 3)));
+        assert_eq!(first_gap_geq::<10>(100), Err(SieveError::TooSmallSieveSize));
+    }
+
+    #[test]
+    fn test_nth_twin_prime() {
+        assert_eq!(nth_twin_prime::<30>(0), Ok((3, 5)));
+        assert_eq!(nth_twin_prime::<30>(1), Ok((5, 7)));
+        assert_eq!(nth_twin_prime::<30>(2), Ok((11, 13)));
+        assert_eq!(nth_twin_prime::<30>(3), Ok((17, 19)));
+        assert_eq!(
+            nth_twin_prime::<10>(100),
+            Err(SieveError::TooSmallSieveSize)
+        );
+    }
+
+    #[test]
+    fn test_find_last_digit_run() {
+        assert_eq!(find_last_digit_run::<300>(3, 2), Ok(283));
+        assert_eq!(find_last_digit_run::<300>(9, 1), Ok(19));
+        assert_eq!(find_last_digit_run::<300>(7, 3), Ok(1627));
+        // A run of zero primes is trivially present before the very first prime.
+        assert_eq!(find_last_digit_run::<300>(3, 0), Ok(2));
+        // No prime ends in 4, so a run of any positive length is never found.
+        assert_eq!(
+            find_last_digit_run::<10>(4, 1),
+            Err(SieveError::TooSmallSieveSize)
+        );
+        // No prime ends in a digit above 9.
+        assert_eq!(
+            find_last_digit_run::<10>(10, 1),
+            Err(SieveError::TooSmallSieveSize)
+        );
+    }
+
+    #[test]
+    fn test_nearest_primes() {
+        assert_eq!(nearest_primes::<30>(20), Ok((Some(19), Some(23))));
+        assert_eq!(nearest_primes::<30>(2), Ok((None, Some(3))));
+        assert_eq!(nearest_primes::<30>(0), Ok((None, Some(2))));
+        // 29 is prime, so its neighbors are the primes on either side of it.
+        assert_eq!(nearest_primes::<30>(29), Ok((Some(23), Some(31))));
+        assert_eq!(nearest_primes::<10>(97), Err(SieveError::TooSmallSieveSize));
+    }
+
     #[test]
     fn test_sieve_geq() {
         assert_eq!(
@@ -492,6 +1410,12 @@ mod test {
             Err(SieveError::TotalDoesntFitU64)
         );
         assert_eq!(sieve_geq::<5, 5>(30), Err(SieveError::TooSmallSieveSize));
-        assert_eq!(sieve_geq::<0, 1>(0), Ok([false; 0]))
+        assert_eq!(sieve_geq::<0, 1>(0), Ok([false; 0]));
+
+        // `MEM`^2 is 16, so the underlying sieve buffer covers up to 17, but only 13, 14 and 15
+        // (all below `MEM`^2) are requested: this must not spuriously error.
+        assert_eq!(sieve_geq::<3, 4>(13), Ok([true, false, false]));
+        // Here the requested window itself reaches `MEM`^2, so it's still correctly rejected.
+        assert_eq!(sieve_geq::<4, 4>(13), Err(SieveError::TooSmallSieveSize));
     }
 }
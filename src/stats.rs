@@ -0,0 +1,127 @@
+//! This module contains small `const fn`s for simple statistics about the first `N` primes.
+
+use crate::primes;
+
+/// Returns the sum of the gaps between the first `N` primes, `p_N - p_1`.
+///
+/// The gaps between consecutive primes telescope, so this is just `p_N - 2`, computed here
+/// with [`primes`] so callers don't have to re-derive the telescoping sum by hand.
+///
+/// Returns `0` if `N == 0`.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// # use const_primes::stats::sum_of_first_gaps;
+/// // The first 5 primes are 2, 3, 5, 7, 11, with gaps 1, 2, 2, 4.
+/// const SUM: u32 = sum_of_first_gaps::<5>();
+/// assert_eq!(SUM, 1 + 2 + 2 + 4);
+/// ```
+#[must_use = "the function only returns a new value"]
+pub const fn sum_of_first_gaps<const N: usize>() -> u32 {
+    if N == 0 {
+        return 0;
+    }
+
+    primes::<N>()[N - 1] - 2
+}
+
+/// Returns the average gap between the first `N` primes, multiplied by `N - 1`, the number of gaps.
+///
+/// This is numerically identical to [`sum_of_first_gaps`], since the average of the `N - 1` gaps
+/// between the first `N` primes times `N - 1` is just their sum. It's provided under this name so
+/// that dividing the result by `N - 1` at the call site reads as computing an average, without
+/// pulling in floating-point division.
+///
+/// Returns `0` if `N == 0`.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// # use const_primes::stats::average_gap_times_n;
+/// // The first 5 primes are 2, 3, 5, 7, 11, with gaps 1, 2, 2, 4, averaging 9/4.
+/// const N: usize = 5;
+/// const AVERAGE_TIMES_N: u32 = average_gap_times_n::<N>();
+/// assert_eq!(AVERAGE_TIMES_N, 9);
+/// assert_eq!(AVERAGE_TIMES_N / (N as u32 - 1), 2); // integer division rounds down
+/// ```
+#[must_use = "the function only returns a new value"]
+pub const fn average_gap_times_n<const N: usize>() -> u32 {
+    sum_of_first_gaps::<N>()
+}
+
+/// Returns how many of the first `N` primes end in each decimal digit `0..=9`, indexed by that
+/// digit.
+///
+/// Built on [`primes`] and `% 10`. Every entry but those for `1, 3, 7, 9` (and `2, 5` themselves)
+/// is `0`, since every prime past `5` ends in one of those four digits; the distribution among
+/// them is the subject of the "prime last-digit bias" (a consequence of
+/// [Chebyshev's bias](https://en.wikipedia.org/wiki/Chebyshev%27s_bias)).
+///
+/// # Example
+///
+/// ```
+/// # use const_primes::stats::last_digit_distribution;
+/// // The first 10 primes are 2, 3, 5, 7, 11, 13, 17, 19, 23, 29.
+/// const DIST: [usize; 10] = last_digit_distribution::<10>();
+/// assert_eq!(DIST, [0, 1, 1, 3, 0, 1, 0, 2, 0, 2]);
+/// ```
+#[must_use = "the function only returns a new value"]
+pub const fn last_digit_distribution<const N: usize>() -> [usize; 10] {
+    let mut distribution = [0; 10];
+
+    let ps: [u32; N] = primes();
+    let mut i = 0;
+    while i < N {
+        distribution[(ps[i] % 10) as usize] += 1;
+        i += 1;
+    }
+
+    distribution
+}
+
+#[cfg(test)]
+mod test {
+    use super::{average_gap_times_n, last_digit_distribution, sum_of_first_gaps};
+
+    #[test]
+    fn check_sum_of_first_gaps() {
+        assert_eq!(sum_of_first_gaps::<0>(), 0);
+        assert_eq!(sum_of_first_gaps::<1>(), 0);
+        assert_eq!(sum_of_first_gaps::<5>(), 9);
+        assert_eq!(sum_of_first_gaps::<10>(), 29 - 2);
+    }
+
+    #[test]
+    fn check_average_gap_times_n() {
+        assert_eq!(average_gap_times_n::<0>(), 0);
+        assert_eq!(average_gap_times_n::<5>(), sum_of_first_gaps::<5>());
+        assert_eq!(average_gap_times_n::<10>(), sum_of_first_gaps::<10>());
+    }
+
+    #[test]
+    fn check_last_digit_distribution() {
+        assert_eq!(last_digit_distribution::<0>(), [0; 10]);
+        assert_eq!(
+            last_digit_distribution::<1>(),
+            [0, 0, 1, 0, 0, 0, 0, 0, 0, 0]
+        ); // just 2
+        assert_eq!(
+            last_digit_distribution::<5>(),
+            [0, 1, 1, 1, 0, 1, 0, 1, 0, 0]
+        ); // 2, 3, 5, 7, 11
+        assert_eq!(
+            last_digit_distribution::<10>(),
+            [0, 1, 1, 3, 0, 1, 0, 2, 0, 2]
+        );
+
+        // Every prime above 5 ends in 1, 3, 7, or 9, so those buckets hold all the rest.
+        let dist = last_digit_distribution::<100>();
+        assert_eq!(dist[0] + dist[2] + dist[4] + dist[5] + dist[6] + dist[8], 2); // just 2 and 5
+        assert_eq!(dist[1] + dist[3] + dist[7] + dist[9], 98);
+    }
+}